@@ -0,0 +1,31 @@
+use std::path::Path;
+
+/// Compiles the grammars in `grammars/`, each exposing `tree_sitter_<name>()`.
+fn main() {
+    compile_grammar("bash", &["parser.c"], &["scanner.cc"]);
+    compile_grammar("regex", &["parser.c"], &[]);
+    compile_grammar("yaml", &["parser.c", "scanner.c"], &[]);
+}
+
+fn compile_grammar(name: &str, c_files: &[&str], cpp_files: &[&str]) {
+    let src_dir = Path::new("grammars").join(name).join("src");
+    println!("cargo:rerun-if-changed={}", src_dir.display());
+
+    let mut c_config = cc::Build::new();
+    c_config
+        .std("c11")
+        .include(&src_dir)
+        .warnings(false)
+        .files(c_files.iter().map(|file| src_dir.join(file)));
+    c_config.compile(&format!("tree-sitter-{name}"));
+
+    if !cpp_files.is_empty() {
+        let mut cpp_config = cc::Build::new();
+        cpp_config
+            .cpp(true)
+            .include(&src_dir)
+            .warnings(false)
+            .files(cpp_files.iter().map(|file| src_dir.join(file)));
+        cpp_config.compile(&format!("tree-sitter-{name}-scanner"));
+    }
+}
@@ -0,0 +1,56 @@
+[
+  (string)
+  (raw_string)
+  (ansii_c_string)
+  (heredoc_body)
+  (heredoc_start)
+] @string
+
+(command_name) @function
+
+(function_definition
+  name: (word) @function)
+
+(variable_name) @variable
+
+[
+  "case"
+  "do"
+  "done"
+  "elif"
+  "else"
+  "esac"
+  "export"
+  "fi"
+  "for"
+  "function"
+  "if"
+  "in"
+  "then"
+  "unset"
+  "while"
+  "declare"
+  "local"
+  "readonly"
+  "typeset"
+] @keyword
+
+(comment) @comment
+
+(file_descriptor) @number
+
+(test_operator) @operator
+
+[
+  "$"
+  "&&"
+  "||"
+  ">"
+  ">>"
+  "<"
+  "|"
+] @operator
+
+((command
+  argument: (word) @constant)
+  (#match? @constant "^-"))
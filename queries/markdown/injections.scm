@@ -0,0 +1,5 @@
+((fenced_code_block
+  (info_string
+    (language) @injection.language)
+  (code_fence_content) @injection.content)
+ (#set! injection.include-children))
@@ -0,0 +1,17 @@
+; Patterns passed to the regex crate
+
+((call_expression
+  function: (scoped_identifier) @_function
+  arguments: (arguments
+    .
+    (string_literal) @injection.content))
+ (#match? @_function "^(regex::)?(Regex|RegexBuilder|RegexSet|RegexSetBuilder)::new$")
+ (#set! injection.language "regex"))
+
+((call_expression
+  function: (scoped_identifier) @_function
+  arguments: (arguments
+    .
+    (raw_string_literal) @injection.content))
+ (#match? @_function "^(regex::)?(Regex|RegexBuilder|RegexSet|RegexSetBuilder)::new$")
+ (#set! injection.language "regex"))
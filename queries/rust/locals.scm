@@ -0,0 +1,30 @@
+; Scopes
+
+[
+  (function_item)
+  (closure_expression)
+  (block)
+  (for_expression)
+  (match_arm)
+] @local.scope
+
+; Definitions
+
+(parameter
+  pattern: (identifier) @local.definition)
+
+(parameter
+  pattern: (mut_pattern (identifier) @local.definition))
+
+(closure_parameters
+  (identifier) @local.definition)
+
+(let_declaration
+  pattern: (identifier) @local.definition)
+
+(for_expression
+  pattern: (identifier) @local.definition)
+
+; References
+
+(identifier) @local.reference
@@ -0,0 +1,103 @@
+use std::{env, fs, io::ErrorKind, path::PathBuf};
+
+use toml::{Table, Value};
+
+use crate::{
+    frontend::{app::App, options::OptionInfo},
+    keys::keymap::MapMode,
+};
+
+/// Returns `$XDG_CONFIG_HOME/ted`, or `~/.config/ted` if the variable is not set.
+pub fn config_dir() -> Option<PathBuf> {
+    let config_home = env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+    Some(config_home.join("ted"))
+}
+
+/// Loads the config file if there is one. Mistakes in it are logged and skipped.
+pub fn load(app: &mut App) {
+    let Some(path) = config_dir().map(|dir| dir.join("config.toml")) else {
+        return;
+    };
+    let content = match fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == ErrorKind::NotFound => return,
+        Err(e) => {
+            app.log
+                .log(format!("Error: Could not read {}: {:?}", path.display(), e));
+            return;
+        }
+    };
+    match content.parse::<Table>() {
+        Ok(config) => {
+            apply(app, &config);
+            app.log
+                .log(format!("[STARTUP] Loaded config from {}", path.display()));
+        }
+        Err(e) => app
+            .log
+            .log(format!("Error: Could not parse {}: {}", path.display(), e)),
+    }
+}
+
+fn apply(app: &mut App, config: &Table) {
+    for (section, value) in config {
+        match (section.as_str(), value) {
+            ("keys", Value::Table(keys)) => apply_keys(app, keys),
+            ("options", Value::Table(options)) => apply_options(app, options),
+            _ => app
+                .log
+                .log(format!("Error: Unknown config section {section}")),
+        }
+    }
+}
+
+/// Applies the `[options]` table, which sets the global value of options like `:set` does.
+fn apply_options(app: &mut App, options: &Table) {
+    for (name, value) in options {
+        let result = OptionInfo::by_name(name)
+            .ok_or_else(|| format!("Unknown option {name}"))
+            .and_then(|option| {
+                let value = match value {
+                    Value::String(s) => option.parse_value(s)?,
+                    Value::Integer(_) | Value::Boolean(_) => {
+                        option.parse_value(&value.to_string())?
+                    }
+                    _ => return Err(format!("Invalid value {value} for {name}")),
+                };
+                app.set_option(option.name, value)
+            });
+        if let Err(e) = result {
+            app.log.log(format!("Error: options.{name}: {e}"));
+        }
+    }
+}
+
+/// Applies the `[keys.<mode>]` tables, which map key sequences to the names of bindings. An
+/// empty name removes the binding.
+fn apply_keys(app: &mut App, keys: &Table) {
+    for (mode_name, bindings) in keys {
+        let Some(mode) = MapMode::by_name(mode_name) else {
+            app.log.log(format!("Error: Unknown mode keys.{mode_name}"));
+            continue;
+        };
+        let Value::Table(bindings) = bindings else {
+            app.log
+                .log(format!("Error: keys.{mode_name} should be a table"));
+            continue;
+        };
+        for (lhs, name) in bindings {
+            let result = match name {
+                Value::String(name) if name.is_empty() => app.keymaps.unmap(mode, lhs),
+                Value::String(name) => app.keymaps.map(mode, lhs, name),
+                _ => Err("the binding should be a string".to_string()),
+            };
+            if let Err(e) = result {
+                app.log
+                    .log(format!("Error: keys.{mode_name} \"{lhs}\": {e}"));
+            }
+        }
+    }
+}
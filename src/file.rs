@@ -0,0 +1,361 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    fmt::Display,
+    fs::{self, File},
+    hash::{Hash, Hasher},
+    io::{self, ErrorKind, Write},
+    path::Path,
+    time::SystemTime,
+};
+
+use ropey::Rope;
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum Encoding {
+    Utf8,
+    Utf16Le,
+    Utf16Be,
+    Latin1,
+}
+
+impl Encoding {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Encoding::Utf8 => "utf-8",
+            Encoding::Utf16Le => "utf-16le",
+            Encoding::Utf16Be => "utf-16be",
+            Encoding::Latin1 => "latin-1",
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum LineEnding {
+    Lf,
+    Crlf,
+}
+
+impl LineEnding {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            LineEnding::Lf => "lf",
+            LineEnding::Crlf => "crlf",
+        }
+    }
+}
+
+/// How a file is stored on disk. The text inside a window always uses `\n` line endings,
+/// this is what it gets converted back to when written.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct FileFormat {
+    pub encoding: Encoding,
+    pub bom: bool,
+    pub line_ending: LineEnding,
+}
+
+impl Default for FileFormat {
+    fn default() -> Self {
+        FileFormat {
+            encoding: Encoding::Utf8,
+            bom: false,
+            line_ending: LineEnding::Lf,
+        }
+    }
+}
+
+impl Display for FileFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}{} {}",
+            self.encoding.display_name(),
+            if self.bom { " bom" } else { "" },
+            self.line_ending.display_name()
+        )
+    }
+}
+
+/// What a file looked like on disk the last time it was read or written.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct DiskState {
+    modified: Option<SystemTime>,
+    hash: u64,
+}
+
+impl DiskState {
+    fn new(path: &Path, bytes: &[u8]) -> io::Result<Self> {
+        Ok(DiskState {
+            modified: fs::metadata(path)?.modified().ok(),
+            hash: hash_bytes(bytes),
+        })
+    }
+}
+
+fn hash_bytes(bytes: &[u8]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    bytes.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Checks whether `path` was modified by someone else since `known` was recorded.
+/// A file without a recorded state counts as changed if it exists now.
+pub fn changed_on_disk(path: &str, known: Option<DiskState>) -> io::Result<bool> {
+    let metadata = match fs::metadata(path) {
+        Ok(metadata) => metadata,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(false),
+        Err(e) => return Err(e),
+    };
+    let Some(known) = known else {
+        return Ok(true);
+    };
+    if known.modified.is_some() && metadata.modified().ok() == known.modified {
+        return Ok(false);
+    }
+    Ok(hash_bytes(&fs::read(path)?) != known.hash)
+}
+
+const UTF8_BOM: &[u8] = &[0xEF, 0xBB, 0xBF];
+const UTF16LE_BOM: &[u8] = &[0xFF, 0xFE];
+const UTF16BE_BOM: &[u8] = &[0xFE, 0xFF];
+
+pub fn load(path: &str) -> io::Result<(Rope, FileFormat, DiskState)> {
+    let bytes = fs::read(path)?;
+    let disk_state = DiskState::new(Path::new(path), &bytes)?;
+    let (text, encoding, bom) = decode(&bytes);
+    let (text, line_ending) = normalize_line_endings(text);
+    Ok((
+        Rope::from_str(text.as_str()),
+        FileFormat {
+            encoding,
+            bom,
+            line_ending,
+        },
+        disk_state,
+    ))
+}
+
+/// Writes `text` to `path` in the given format and returns the number of bytes written.
+///
+/// The text is written to a temporary file next to the target first, which is synced and then
+/// renamed over the target, so the original file stays intact if anything goes wrong midway.
+pub fn save(path: &str, text: &Rope, format: FileFormat) -> io::Result<(usize, DiskState)> {
+    let bytes = encode(text, format)?;
+    let target = match fs::canonicalize(path) {
+        Ok(resolved) => resolved,
+        Err(e) if e.kind() == ErrorKind::NotFound => Path::new(path).to_path_buf(),
+        Err(e) => return Err(e),
+    };
+    let directory = match target.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+
+    let mut temp_file = tempfile::Builder::new()
+        .prefix(".ted_write_")
+        .tempfile_in(directory)?;
+    temp_file.write_all(&bytes)?;
+    temp_file.as_file().sync_all()?;
+    if let Ok(metadata) = fs::metadata(&target) {
+        temp_file
+            .as_file()
+            .set_permissions(metadata.permissions())?;
+    } else {
+        set_new_file_permissions(temp_file.as_file())?;
+    }
+    temp_file.persist(&target).map_err(|e| e.error)?;
+    // Make the rename itself durable. Not every platform can open a directory, which is fine.
+    if let Ok(dir) = File::open(directory) {
+        let _ = dir.sync_all();
+    }
+
+    Ok((bytes.len(), DiskState::new(&target, &bytes)?))
+}
+
+/// Gives a new file the mode it would have had if it was created directly, instead of the
+/// tempfile's 0600.
+#[cfg(unix)]
+fn set_new_file_permissions(file: &File) -> io::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    file.set_permissions(fs::Permissions::from_mode(0o666 & !umask()))
+}
+
+#[cfg(not(unix))]
+fn set_new_file_permissions(_file: &File) -> io::Result<()> {
+    Ok(())
+}
+
+/// Reads the process umask without changing it, falling back to the common 022.
+#[cfg(unix)]
+fn umask() -> u32 {
+    fs::read_to_string("/proc/self/status")
+        .ok()
+        .and_then(|status| {
+            let line = status.lines().find(|line| line.starts_with("Umask:"))?;
+            u32::from_str_radix(line["Umask:".len()..].trim(), 8).ok()
+        })
+        .unwrap_or(0o022)
+}
+
+fn decode(bytes: &[u8]) -> (String, Encoding, bool) {
+    if let Some(rest) = bytes.strip_prefix(UTF8_BOM) {
+        if let Ok(s) = std::str::from_utf8(rest) {
+            return (s.to_string(), Encoding::Utf8, true);
+        }
+    }
+    if let Some(rest) = bytes.strip_prefix(UTF16LE_BOM) {
+        return (
+            decode_utf16(rest, u16::from_le_bytes),
+            Encoding::Utf16Le,
+            true,
+        );
+    }
+    if let Some(rest) = bytes.strip_prefix(UTF16BE_BOM) {
+        return (
+            decode_utf16(rest, u16::from_be_bytes),
+            Encoding::Utf16Be,
+            true,
+        );
+    }
+    if let Some(encoding) = guess_utf16_without_bom(bytes) {
+        let text = match encoding {
+            Encoding::Utf16Le => decode_utf16(bytes, u16::from_le_bytes),
+            _ => decode_utf16(bytes, u16::from_be_bytes),
+        };
+        return (text, encoding, false);
+    }
+    match std::str::from_utf8(bytes) {
+        Ok(s) => (s.to_string(), Encoding::Utf8, false),
+        Err(_) => (
+            bytes.iter().map(|b| *b as char).collect(),
+            Encoding::Latin1,
+            false,
+        ),
+    }
+}
+
+fn decode_utf16(bytes: &[u8], to_unit: fn([u8; 2]) -> u16) -> String {
+    let units = bytes
+        .chunks_exact(2)
+        .map(|pair| to_unit([pair[0], pair[1]]));
+    char::decode_utf16(units)
+        .map(|r| r.unwrap_or(char::REPLACEMENT_CHARACTER))
+        .collect()
+}
+
+/// Text without a BOM is considered UTF-16 if most of its code units have a zero high byte,
+/// which is what ASCII-heavy text looks like in UTF-16.
+fn guess_utf16_without_bom(bytes: &[u8]) -> Option<Encoding> {
+    if bytes.len() < 2 || !bytes.len().is_multiple_of(2) {
+        return None;
+    }
+    let sample = &bytes[..bytes.len().min(1024)];
+    let pairs = sample.len() / 2;
+    let even_zeros = sample.iter().step_by(2).filter(|b| **b == 0).count();
+    let odd_zeros = sample
+        .iter()
+        .skip(1)
+        .step_by(2)
+        .filter(|b| **b == 0)
+        .count();
+    if odd_zeros * 10 >= pairs * 7 && even_zeros == 0 {
+        Some(Encoding::Utf16Le)
+    } else if even_zeros * 10 >= pairs * 7 && odd_zeros == 0 {
+        Some(Encoding::Utf16Be)
+    } else {
+        None
+    }
+}
+
+fn normalize_line_endings(text: String) -> (String, LineEnding) {
+    let crlf = text.matches("\r\n").count();
+    let lf = text.matches('\n').count() - crlf;
+    if crlf > lf {
+        (text.replace("\r\n", "\n"), LineEnding::Crlf)
+    } else {
+        (text, LineEnding::Lf)
+    }
+}
+
+fn encode(text: &Rope, format: FileFormat) -> io::Result<Vec<u8>> {
+    let mut text = text.to_string();
+    if format.line_ending == LineEnding::Crlf {
+        text = text.replace('\n', "\r\n");
+    }
+    let mut bytes = Vec::with_capacity(text.len() + 3);
+    match format.encoding {
+        Encoding::Utf8 => {
+            if format.bom {
+                bytes.extend_from_slice(UTF8_BOM);
+            }
+            bytes.extend_from_slice(text.as_bytes());
+        }
+        Encoding::Utf16Le => {
+            if format.bom {
+                bytes.extend_from_slice(UTF16LE_BOM);
+            }
+            text.encode_utf16()
+                .for_each(|unit| bytes.extend_from_slice(&unit.to_le_bytes()));
+        }
+        Encoding::Utf16Be => {
+            if format.bom {
+                bytes.extend_from_slice(UTF16BE_BOM);
+            }
+            text.encode_utf16()
+                .for_each(|unit| bytes.extend_from_slice(&unit.to_be_bytes()));
+        }
+        Encoding::Latin1 => {
+            for c in text.chars() {
+                match u8::try_from(c as u32) {
+                    Ok(b) => bytes.push(b),
+                    Err(_) => {
+                        return Err(io::Error::new(
+                            ErrorKind::InvalidData,
+                            format!("'{c}' can not be encoded as latin-1"),
+                        ))
+                    }
+                }
+            }
+        }
+    }
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(unix)]
+    #[test]
+    fn save_new_file_uses_default_mode() {
+        use std::os::unix::fs::PermissionsExt;
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("new.txt");
+        save(
+            path.to_str().unwrap(),
+            &Rope::from_str("text\n"),
+            FileFormat::default(),
+        )
+        .unwrap();
+        let mode = fs::metadata(&path).unwrap().permissions().mode() & 0o777;
+        assert_eq!(mode, 0o666 & !umask());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn save_keeps_mode_of_existing_file() {
+        use std::os::unix::fs::PermissionsExt;
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("script.sh");
+        fs::write(&path, "old\n").unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o751)).unwrap();
+        save(
+            path.to_str().unwrap(),
+            &Rope::from_str("new\n"),
+            FileFormat::default(),
+        )
+        .unwrap();
+        let mode = fs::metadata(&path).unwrap().permissions().mode() & 0o777;
+        assert_eq!(mode, 0o751);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "new\n");
+    }
+}
@@ -1,19 +1,61 @@
-use std::sync::mpsc::Sender;
+use std::{cell::RefCell, fs, io, rc::Rc, time::Instant};
 
-use ropey::Rope;
-
-use crate::log::Log;
+use crate::{
+    file,
+    keys::{
+        ex::Substitution,
+        keymap::{KeyChord, Keymaps},
+        motion::FindChar,
+    },
+    log::Log,
+};
 
 use super::{
+    buffer::Buffer,
     dialog::Dialog,
-    window::{HighlightJob, Window},
+    grep::Grep,
+    language::Language,
+    layout::{PaneDirection, SplitDirection},
+    options::{OptionValue, Options},
+    register::Registers,
+    search::Search,
+    tab::TabPage,
+    theme::Theme,
+    window::Window,
 };
 
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum VisualKind {
+    Char,
+    Line,
+    Block,
+}
+
 pub enum Mode {
     Normal,
     Insert,
-    Dialog { which_one: Dialog },
-    Command { buffer: String, char_idx: usize },
+    Visual {
+        kind: VisualKind,
+    },
+    Dialog {
+        which_one: Dialog,
+    },
+    Command {
+        buffer: String,
+        char_idx: usize,
+    },
+    /// The prompt of `/` and `?`, the cursor moves to the first match while typing and goes
+    /// back to `origin` when the search is cancelled
+    Search {
+        buffer: String,
+        char_idx: usize,
+        backward: bool,
+        origin: usize,
+    },
+    /// Asks about each match of `:s///c` before replacing it
+    ConfirmSubstitute {
+        substitution: Substitution,
+    },
 }
 
 impl Mode {
@@ -21,8 +63,19 @@ impl Mode {
         match &self {
             Mode::Normal => "NORMAL",
             Mode::Insert => "INSERT",
+            Mode::Visual {
+                kind: VisualKind::Char,
+            } => "VISUAL",
+            Mode::Visual {
+                kind: VisualKind::Line,
+            } => "VISUAL LINE",
+            Mode::Visual {
+                kind: VisualKind::Block,
+            } => "VISUAL BLOCK",
             Mode::Dialog { .. } => "DIALOG",
             Mode::Command { .. } => "COMMAND",
+            Mode::Search { .. } => "SEARCH",
+            Mode::ConfirmSubstitute { .. } => "CONFIRM",
         }
     }
 }
@@ -33,44 +86,346 @@ pub struct App {
     pub selected_window: usize,
     pub log: Log,
     pub current_mode: Mode,
-    pub highlight_job_queue: Sender<HighlightJob>,
+    pub registers: Registers,
+    pub keymaps: Keymaps,
+    pub pending_keys: Vec<KeyChord>,
+    pub pending_keys_since: Option<Instant>,
+    /// The last `f`, `t`, `F` or `T`, which `;` and `,` repeat
+    pub last_find: Option<FindChar>,
+    pub search: Option<Search>,
+    /// Whether the matches of the last search are highlighted, until `:noh`
+    pub highlight_search: bool,
+    /// The results of the last `:grep`
+    pub grep: Option<Grep>,
+    pub tabs: Vec<TabPage>,
+    pub selected_tab: usize,
+    pub options: Options,
+    pub theme: Rc<Theme>,
 }
 
 impl App {
+    pub fn new() -> Self {
+        App {
+            uuid_counter: 0,
+            edit_windows: Vec::new(),
+            selected_window: 0,
+            log: Log::new(),
+            current_mode: Mode::Normal,
+            registers: Registers::new(),
+            keymaps: Keymaps::new(),
+            pending_keys: Vec::new(),
+            pending_keys_since: None,
+            last_find: None,
+            search: None,
+            highlight_search: false,
+            grep: None,
+            tabs: vec![TabPage::new(0, None)],
+            selected_tab: 0,
+            options: Options::new(),
+            theme: Rc::new(Theme::default()),
+        }
+    }
+
     pub fn has_modified_windows(&self) -> bool {
-        self.edit_windows.iter().any(|w| w.modified)
+        self.edit_windows.iter().any(|w| w.buffer().modified)
     }
 
-    pub fn create_empty_window(&mut self) -> usize {
-        let window = Window {
-            uuid: self.uuid_counter,
-            ident: None,
-            text: Rope::new(),
-            scroll_x: 0,
-            scroll_y: 0,
-            cursor_char_index: 0,
-            attached_file_path: None,
-            modified: false,
-            language: None,
-            highlight_data: None,
-        };
+    /// Sets the global value of an option, switching to the theme the `theme` option names.
+    pub fn set_option(&mut self, name: &str, value: OptionValue) -> Result<(), String> {
+        if let ("theme", OptionValue::String(theme)) = (name, &value) {
+            self.theme = Rc::new(Theme::load(theme)?);
+        }
+        self.options.set(name, value)
+    }
+
+    fn next_uuid(&mut self) -> usize {
         self.uuid_counter += 1;
+        self.uuid_counter - 1
+    }
+
+    pub fn create_empty_window(&mut self) -> usize {
+        let buffer = Buffer::new(self.options.buffer);
+        self.create_window(Rc::new(RefCell::new(buffer)))
+    }
+
+    /// Adds a window showing `buffer` and returns its index.
+    pub fn create_window(&mut self, buffer: Rc<RefCell<Buffer>>) -> usize {
+        let window = Window::new(self.next_uuid(), buffer);
         self.edit_windows.push(window);
         self.edit_windows.len() - 1
     }
 
+    /// Returns the index of a window attached to `path`.
+    fn find_window_of_file(&self, path: &str) -> Option<usize> {
+        let canonical = fs::canonicalize(path).ok();
+        self.edit_windows.iter().position(|w| {
+            w.buffer()
+                .attached_file_path
+                .as_deref()
+                .is_some_and(|attached| {
+                    attached == path
+                        || canonical.is_some() && fs::canonicalize(attached).ok() == canonical
+                })
+        })
+    }
+
+    /// Opens `path` in a new window and selects it. If the file is already open, the new
+    /// window shows the same buffer instead of loading it again.
+    pub fn open_file(&mut self, path: &str) -> io::Result<usize> {
+        if let Some(open_window) = self.find_window_of_file(path) {
+            let buffer = Rc::clone(&self.edit_windows[open_window].buffer);
+            let window_index = self.create_window(buffer);
+            self.selected_window = window_index;
+            return Ok(window_index);
+        }
+        let (text, file_format, disk_state) = file::load(path)?;
+        let window_index = self.create_empty_window();
+        let mut buffer = self.edit_windows[window_index].buffer_mut();
+        buffer.text = text;
+        buffer.file_format = file_format;
+        buffer.disk_state = Some(disk_state);
+        buffer.attached_file_path = Some(path.to_string());
+        buffer.detect_indentation();
+        drop(buffer);
+        self.selected_window = window_index;
+        Ok(window_index)
+    }
+
+    /// Selects the window attached to `path`, or opens it in a new window if there is none.
+    pub fn select_or_open_file(&mut self, path: &str) -> io::Result<usize> {
+        let window_index = match self.find_window_of_file(path) {
+            Some(window_index) => window_index,
+            None => {
+                let window_index = self.open_file(path)?;
+                self.detect_language(window_index);
+                window_index
+            }
+        };
+        self.selected_window = window_index;
+        Ok(window_index)
+    }
+
+    /// Detects the language of the window at `window_index`, logging it if its queries are
+    /// broken so it's clear why the text isn't highlighted.
+    pub fn detect_language(&mut self, window_index: usize) -> Option<Language> {
+        let language = self.edit_windows[window_index].try_detect_langauge()?;
+        if let Err(e) = language.queries() {
+            self.log.log(format!("Error: {e}"));
+        }
+        Some(language)
+    }
+
     pub fn close_selected(&mut self) -> Window {
-        let w = self.edit_windows.remove(self.selected_window);
+        self.close_window(self.selected_window)
+    }
+
+    /// Closes the window at `index`. The selection stays on the same window, or goes to the
+    /// previous one if the selected window is closed.
+    pub fn close_window(&mut self, index: usize) -> Window {
+        let w = self.edit_windows.remove(index);
         if self.edit_windows.is_empty() {
             self.selected_window = 0
         } else if self.selected_window >= self.edit_windows.len() {
             self.selected_window = self.edit_windows.len() - 1;
-        } else if self.selected_window > 0 {
+        } else if self.selected_window > 0 && self.selected_window >= index {
             self.selected_window -= 1;
         }
         w
     }
 
+    /// Makes the focused pane show the selected window and the panes of closed windows too.
+    pub fn sync_panes(&mut self) {
+        let Some(selected_uuid) = self.selected_window().map(|sw| sw.uuid) else {
+            return;
+        };
+        let panes = &mut self.tabs[self.selected_tab].panes;
+        let focused = panes.focused;
+        for pane in panes.panes_mut() {
+            let window = self
+                .edit_windows
+                .iter()
+                .find(|w| w.uuid == pane.window_uuid);
+            if pane.id == focused || window.is_none() {
+                pane.window_uuid = selected_uuid;
+            }
+        }
+    }
+
+    /// Moves the focus to pane `id`.
+    pub fn focus_pane(&mut self, id: usize) {
+        self.sync_panes();
+        self.tab_mut().panes.focused = id;
+        self.show_focused_pane();
+    }
+
+    /// Selects the window of the focused pane.
+    fn show_focused_pane(&mut self) {
+        let uuid = self.tab_mut().panes.focused_pane_mut().window_uuid;
+        if let Some(index) = self.edit_windows.iter().position(|w| w.uuid == uuid) {
+            self.selected_window = index;
+            self.edit_windows[index].follow_buffer();
+        }
+    }
+
+    pub fn focus_pane_in_direction(&mut self, direction: PaneDirection) {
+        if let Some(id) = self.tab().panes.neighbour(direction) {
+            self.focus_pane(id);
+        }
+    }
+
+    pub fn focus_next_pane(&mut self) {
+        let id = self.tab().panes.next();
+        self.focus_pane(id);
+    }
+
+    /// Splits the focused pane. The new half shows a new window on the buffer of the selected
+    /// one, starting at the same position.
+    pub fn split_pane(&mut self, direction: SplitDirection) {
+        self.sync_panes();
+        let Some(sw) = self.selected_window() else {
+            self.log.log("Error: No open windows");
+            return;
+        };
+        let buffer = Rc::clone(&sw.buffer);
+        let (cursor, anchor, scroll_x, scroll_y, desired_column, visual_marks) = (
+            sw.cursor_char_index,
+            sw.selection_anchor,
+            sw.scroll_x,
+            sw.scroll_y,
+            sw.desired_column,
+            sw.visual_marks,
+        );
+        let window_index = self.create_window(buffer);
+        let window = &mut self.edit_windows[window_index];
+        window.cursor_char_index = cursor;
+        window.selection_anchor = anchor;
+        window.scroll_x = scroll_x;
+        window.scroll_y = scroll_y;
+        window.desired_column = desired_column;
+        window.visual_marks = visual_marks;
+        let uuid = window.uuid;
+        self.tab_mut().panes.split(direction, uuid);
+        self.selected_window = window_index;
+    }
+
+    /// Closes the focused pane, returns false if it is the last one.
+    pub fn close_pane(&mut self) -> bool {
+        self.sync_panes();
+        let uuid = self.tab_mut().panes.focused_pane_mut().window_uuid;
+        if !self.tab_mut().panes.close_focused() {
+            return false;
+        }
+        self.close_hidden_view(uuid);
+        self.show_focused_pane();
+        true
+    }
+
+    /// Closes all panes but the focused one.
+    pub fn only_pane(&mut self) {
+        self.sync_panes();
+        let focused = self.tab().panes.focused;
+        let closed: Vec<usize> = self
+            .tab_mut()
+            .panes
+            .panes_mut()
+            .into_iter()
+            .filter(|pane| pane.id != focused)
+            .map(|pane| pane.window_uuid)
+            .collect();
+        self.tab_mut().panes.only();
+        for uuid in closed {
+            self.close_hidden_view(uuid);
+        }
+        self.show_focused_pane();
+    }
+
+    /// Closes window `uuid` if no pane shows it and another window shows its buffer, so
+    /// closing a pane doesn't leave extra windows behind.
+    fn close_hidden_view(&mut self, uuid: usize) {
+        let shown = self.tabs.iter().any(|tab| {
+            tab.panes
+                .panes(tab.panes.area)
+                .iter()
+                .any(|(_, pane)| pane.window_uuid == uuid)
+        });
+        let index = self.edit_windows.iter().position(|w| w.uuid == uuid);
+        if let Some(index) = index {
+            if !shown && self.edit_windows[index].shares_buffer() {
+                self.close_window(index);
+            }
+        }
+    }
+
+    pub fn tab(&self) -> &TabPage {
+        &self.tabs[self.selected_tab]
+    }
+
+    pub fn tab_mut(&mut self) -> &mut TabPage {
+        &mut self.tabs[self.selected_tab]
+    }
+
+    /// Switches to tab `index` and selects the window of its focused pane.
+    pub fn select_tab(&mut self, index: usize) {
+        self.sync_panes();
+        self.selected_tab = index;
+        self.show_focused_pane();
+        self.sync_panes();
+    }
+
+    pub fn next_tab(&mut self) {
+        self.select_tab((self.selected_tab + 1) % self.tabs.len());
+    }
+
+    /// Goes `count` tabs back, wrapping around at the first one.
+    pub fn previous_tab(&mut self, count: usize) {
+        let len = self.tabs.len();
+        self.select_tab((self.selected_tab + len - count % len) % len);
+    }
+
+    /// Opens a tab after the current one, showing a new empty window. It starts out in the
+    /// working directory of the current tab.
+    pub fn new_tab(&mut self) {
+        self.sync_panes();
+        let window_index = self.create_empty_window();
+        let tab = TabPage::new(self.edit_windows[window_index].uuid, self.tab().cwd.clone());
+        self.tabs.insert(self.selected_tab + 1, tab);
+        self.selected_tab += 1;
+        self.selected_window = window_index;
+    }
+
+    /// Closes the current tab, returns false if it is the last one. The windows it showed stay
+    /// open unless other windows show their buffers.
+    pub fn close_tab(&mut self) -> bool {
+        if self.tabs.len() == 1 {
+            return false;
+        }
+        self.sync_panes();
+        let tab = self.tabs.remove(self.selected_tab);
+        self.selected_tab = self.selected_tab.min(self.tabs.len() - 1);
+        for (_, pane) in tab.panes.panes(tab.panes.area) {
+            self.close_hidden_view(pane.window_uuid);
+        }
+        self.show_focused_pane();
+        self.sync_panes();
+        true
+    }
+
+    /// Remembers that the selected window is in use, for the window picker.
+    pub fn mark_selected_window_used(&mut self) {
+        if let Some(sw) = self.selected_window_mut() {
+            sw.last_used = Instant::now();
+        }
+    }
+
+    /// Returns the pattern whose matches are highlighted, the one being typed while searching.
+    pub fn search_pattern(&self) -> Option<&str> {
+        match &self.current_mode {
+            Mode::Search { buffer, .. } if !buffer.is_empty() => Some(buffer),
+            _ if self.highlight_search => self.search.as_ref().map(|s| s.pattern.as_str()),
+            _ => None,
+        }
+    }
+
     pub fn selected_window(&self) -> Option<&'_ Window> {
         if self.edit_windows.is_empty() {
             None
@@ -87,32 +442,51 @@ impl App {
         }
     }
 
-    pub fn queue_selected_window_highlight_refresh(&self) {
-        if let Some(sw) = self.selected_window() {
-            if let Some(lang) = &sw.language {
-                self.highlight_job_queue
-                    .send(HighlightJob {
-                        text: sw.text.clone(),
-                        window_uuid: sw.uuid,
-                        language: *lang,
-                    })
-                    .expect("Could not send highlight job");
-            }
+    /// Goes `count` windows back, wrapping around at the first one.
+    pub fn previous_window(&mut self, count: usize) {
+        let len = self.edit_windows.len();
+        if len > 0 {
+            self.selected_window = (self.selected_window + len - count % len) % len;
         }
     }
 
-    pub fn previous_window(&mut self) {
-        if self.selected_window > 0 {
-            self.selected_window -= 1;
-        } else if !self.edit_windows.is_empty() {
-            self.selected_window = self.edit_windows.len() - 1;
+    /// Goes `count` windows forward, wrapping around at the last one.
+    pub fn next_window(&mut self, count: usize) {
+        let len = self.edit_windows.len();
+        if len > 0 {
+            self.selected_window = (self.selected_window + count % len) % len;
         }
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::App;
 
-    pub fn next_window(&mut self) {
-        self.selected_window += 1;
-        if self.selected_window >= self.edit_windows.len() {
-            self.selected_window = 0;
+    #[test]
+    fn counted_window_switch_wraps_around() {
+        let mut app = App::new();
+        for _ in 0..3 {
+            app.create_empty_window();
         }
+        app.next_window(4);
+        assert_eq!(app.selected_window, 1);
+        app.previous_window(2);
+        assert_eq!(app.selected_window, 2);
+        app.next_window(usize::MAX);
+        assert_eq!(app.selected_window, 2);
+    }
+
+    #[test]
+    fn counted_tab_switch_wraps_around() {
+        let mut app = App::new();
+        app.selected_window = app.create_empty_window();
+        app.new_tab();
+        app.new_tab();
+        assert_eq!(app.selected_tab, 2);
+        app.previous_tab(4);
+        assert_eq!(app.selected_tab, 1);
+        app.previous_tab(usize::MAX);
+        assert_eq!(app.selected_tab, 1);
     }
 }
@@ -0,0 +1,214 @@
+use std::{collections::VecDeque, ops::Range};
+
+use ropey::Rope;
+use tree_sitter::Tree;
+
+use crate::file::{DiskState, FileFormat};
+
+use super::{
+    history::{Edit, History},
+    language::Language,
+    options::BufferOptions,
+    syntax::{self, Syntax},
+};
+
+/// How many of the latest edits a buffer remembers for the windows showing it
+const RECENT_EDITS: usize = 1000;
+
+/// The text of a file or scratch window along with everything that belongs to it rather than
+/// to a view of it. Windows showing the same buffer share edits, undo and highlighting.
+pub struct Buffer {
+    pub ident: Option<String>,
+    pub text: Rope,
+    pub attached_file_path: Option<String>,
+    pub modified: bool,
+    pub language: Option<Language>,
+    /// The syntax tree and highlights, made for `language` when they are first needed
+    syntax: Option<Syntax>,
+    pub history: History,
+    pub options: BufferOptions,
+    pub file_format: FileFormat,
+    pub disk_state: Option<DiskState>,
+    /// Counts the edits of the text, so results of background work can be matched to it
+    pub text_version: usize,
+    /// The latest edits and the `text_version` each of them led to
+    recent_edits: VecDeque<(usize, Edit)>,
+}
+
+impl Buffer {
+    pub fn new(options: BufferOptions) -> Self {
+        Buffer {
+            ident: None,
+            text: Rope::new(),
+            attached_file_path: None,
+            modified: false,
+            language: None,
+            syntax: None,
+            history: History::new(),
+            options,
+            file_format: FileFormat::default(),
+            disk_state: None,
+            text_version: 0,
+            recent_edits: VecDeque::new(),
+        }
+    }
+
+    /// Detects the language by the name of the attached file or by the `#!` line. A newly
+    /// detected language brings its indent rules, unless the text is indented otherwise.
+    pub fn try_detect_langauge(&mut self) -> Option<&Language> {
+        let detected = self
+            .attached_file_path
+            .as_deref()
+            .and_then(Language::by_file_name)
+            .or_else(|| {
+                let first_line = self.text.get_line(0)?.to_string();
+                Language::by_shebang(first_line.trim_end())
+            });
+        if let Some(language) = detected.filter(|language| self.language != Some(*language)) {
+            self.language = Some(language);
+            if let Some(indent) = language.indent() {
+                self.options.expandtab = !indent.tabs;
+                if let Some(width) = indent.width {
+                    self.options.tabstop = width;
+                }
+                self.detect_indentation();
+            }
+        }
+        self.language.as_ref()
+    }
+
+    pub fn resolve_title(&self) -> &str {
+        self.ident
+            .as_ref()
+            .or(self.attached_file_path.as_ref())
+            .map(|s| s.as_str())
+            .unwrap_or("Untitled")
+    }
+
+    pub fn insert(&mut self, char_idx: usize, text: &str) {
+        if text.is_empty() {
+            return;
+        }
+        self.text.insert(char_idx, text);
+        let edit = Edit::Insert {
+            char_idx,
+            text: text.to_string(),
+        };
+        self.history.record(edit.clone());
+        self.text_changed(vec![edit]);
+    }
+
+    pub fn remove(&mut self, range: std::ops::Range<usize>) {
+        if range.is_empty() {
+            return;
+        }
+        let text = self.text.slice(range.clone()).to_string();
+        self.text.remove(range.clone());
+        let edit = Edit::Remove {
+            char_idx: range.start,
+            text,
+        };
+        self.history.record(edit.clone());
+        self.text_changed(vec![edit]);
+    }
+
+    /// Reverts the last change and returns where it happened.
+    pub fn undo(&mut self) -> Option<usize> {
+        let (position, edits) = self.history.undo(&mut self.text)?;
+        self.text_changed(edits);
+        Some(position)
+    }
+
+    /// Reapplies the last undone change and returns where it happened.
+    pub fn redo(&mut self) -> Option<usize> {
+        let (position, edits) = self.history.redo(&mut self.text)?;
+        self.text_changed(edits);
+        Some(position)
+    }
+
+    fn text_changed(&mut self, edits: Vec<Edit>) {
+        self.modified = !self.history.is_saved();
+        if let Some(syntax) = &mut self.syntax {
+            // Each edit is described in the text right after it, which is found by undoing the
+            // later edits on a copy of the text
+            let mut text = self.text.clone();
+            let mut input_edits = Vec::with_capacity(edits.len());
+            for edit in edits.iter().rev() {
+                input_edits.push(syntax::input_edit(edit, &text));
+                edit.invert().apply(&mut text);
+            }
+            for input_edit in input_edits.iter().rev() {
+                syntax.edit(input_edit);
+            }
+        }
+        for edit in edits {
+            self.text_version += 1;
+            self.recent_edits.push_back((self.text_version, edit));
+        }
+        while self.recent_edits.len() > RECENT_EDITS {
+            self.recent_edits.pop_front();
+        }
+    }
+
+    /// Returns the edits made since `text_version`, or `None` if they are too many to
+    /// remember.
+    pub fn edits_since(&self, text_version: usize) -> Option<Vec<&Edit>> {
+        if text_version == self.text_version {
+            return Some(Vec::new());
+        }
+        let oldest = self.recent_edits.front()?.0;
+        if oldest > text_version + 1 {
+            return None;
+        }
+        Some(
+            self.recent_edits
+                .iter()
+                .filter(|(version, _)| *version > text_version)
+                .map(|(_, edit)| edit)
+                .collect(),
+        )
+    }
+
+    /// Replaces the syntax when the language of the buffer changed.
+    fn sync_syntax(&mut self) {
+        if self.syntax.as_ref().map(|syntax| syntax.language) != self.language {
+            self.syntax = self.language.and_then(Syntax::new);
+        }
+    }
+
+    /// Returns the syntax tree of the text, parsing what changed since it was last parsed.
+    pub fn syntax_tree(&mut self) -> Option<Tree> {
+        self.sync_syntax();
+        let syntax = self.syntax.as_mut()?;
+        syntax.update(&self.text);
+        syntax.tree().cloned()
+    }
+
+    /// Brings the highlights up to date, starting with the lines in `visible_lines`.
+    pub fn highlight(&mut self, visible_lines: Range<usize>) {
+        self.sync_syntax();
+        let Some(syntax) = &mut self.syntax else {
+            return;
+        };
+        let end_line = visible_lines.end.min(self.text.len_lines());
+        let visible = self.text.line_to_byte(visible_lines.start.min(end_line))
+            ..self.text.line_to_byte(end_line);
+        syntax.highlight(&self.text, visible);
+    }
+
+    /// Returns the capture name the byte at `byte_index` is highlighted as.
+    pub fn find_highlight(&self, byte_index: usize) -> Option<&'static str> {
+        self.syntax.as_ref()?.find_highlight(byte_index)
+    }
+
+    /// Switches to tab indentation if any line of the text is indented with a tab.
+    pub fn detect_indentation(&mut self) {
+        if self
+            .text
+            .lines()
+            .any(|line| line.chars().next() == Some('\t'))
+        {
+            self.options.expandtab = false;
+        }
+    }
+}
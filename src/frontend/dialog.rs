@@ -1,96 +1,272 @@
 use ratatui::{
     layout::Rect,
-    style::{Color, Style, Stylize},
+    style::Style,
     text::{Line, Span},
     widgets::{Block, Borders, Clear, Paragraph, Widget},
     Frame,
 };
 
-use super::{app::App, COMMAND_MODE_BACKGROUND};
+use crate::keys::keymap::{display_sequence, MapMode};
+
+use super::{
+    app::App,
+    finder::FileFinder,
+    picker::{label, WindowPicker},
+    register::RegisterKind,
+    theme::{Theme, UiElement},
+};
 
 pub enum Dialog {
     Logs,
-    Windows,
+    Windows(WindowPicker),
+    Registers,
+    SearchResults,
+    FileFinder(FileFinder),
+    /// The bindings of every mode, scrolled down by `scroll` lines
+    Keymaps {
+        scroll: usize,
+    },
 }
 
 impl Dialog {
-    fn create_block() -> Block<'static> {
+    fn create_block(theme: &Theme) -> Block<'static> {
         Block::default()
-            .title_style(Style::new().fg(ratatui::style::Color::Yellow))
+            .title_style(theme.ui(UiElement::DialogTitle))
+            .border_style(theme.ui(UiElement::DialogBorder))
             .borders(Borders::all())
     }
 
     pub fn render(&self, app: &App, terminal: &mut Frame<'_>, area: Rect) {
         Clear.render(area, terminal.buffer_mut());
+        let theme = &app.theme;
         match self {
-            Dialog::Windows => {
-                const SCROLL_BORDERS: usize = 3;
-
-                let mut to_skip = if app.selected_window < SCROLL_BORDERS {
-                    0
+            Dialog::Windows(picker) => {
+                let title = if picker.filtering || !picker.query.is_empty() {
+                    format!(
+                        "Windows {}/{} /{}",
+                        picker.entries.len(),
+                        app.edit_windows.len(),
+                        picker.query
+                    )
                 } else {
-                    app.selected_window - SCROLL_BORDERS
+                    format!("Windows {}", app.edit_windows.len())
                 };
-
-                let last_window_seen =
-                    (to_skip + area.height as usize - 2).min(app.edit_windows.len());
-                if last_window_seen > area.height as usize + 2
-                    && last_window_seen == app.edit_windows.len()
-                {
-                    to_skip = last_window_seen - area.height as usize - 2 + SCROLL_BORDERS + 1;
-                }
-                let block = Dialog::create_block().title(format!("Windows {last_window_seen}"));
-
-                let lines = app
-                    .edit_windows
+                let height = (area.height as usize).saturating_sub(2);
+                let to_skip = (picker.selected + 1).saturating_sub(height);
+                let lines: Vec<Line> = picker
+                    .entries
                     .iter()
-                    .skip(to_skip)
-                    .take(area.height as usize)
                     .enumerate()
-                    .map(|(idx, window)| {
-                        let is_selcted = app.selected_window == idx + to_skip;
+                    .skip(to_skip)
+                    .take(height)
+                    .map(|(idx, (window_index, fuzzy_match))| {
+                        let window = &app.edit_windows[*window_index];
+                        let mut spans: Vec<Span> = label(window)
+                            .chars()
+                            .enumerate()
+                            .map(|(i, c)| {
+                                let span = Span::from(c.to_string());
+                                if fuzzy_match.positions.contains(&i) {
+                                    styled(span, theme.ui(UiElement::DialogMatch))
+                                } else {
+                                    span
+                                }
+                            })
+                            .collect();
+                        spans.push(Span::from(" ["));
+                        spans.push(Span::styled(
+                            window
+                                .buffer()
+                                .language
+                                .map_or("Text", |l| l.display_name()),
+                            theme.ui(UiElement::DialogDetail),
+                        ));
+                        spans.push(Span::from(format!(", {} lines", window.text().len_lines())));
+                        if *window_index == app.selected_window {
+                            spans.push(Span::from(", current"));
+                        }
+                        if window.buffer().modified {
+                            spans.push(Span::from(", "));
+                            spans.push(Span::styled("modified", theme.ui(UiElement::Modified)));
+                        }
+                        spans.push(Span::from("]"));
 
+                        if idx == picker.selected {
+                            spans = spans
+                                .into_iter()
+                                .map(|s| styled(s, theme.ui(UiElement::DialogSelection)))
+                                .collect();
+                        }
+                        Line::from(spans)
+                    })
+                    .collect();
+                let block = Dialog::create_block(theme).title(title);
+                terminal.render_widget(Paragraph::new(lines).block(block), area);
+            }
+            Dialog::Registers => {
+                let lines: Vec<Line> = app
+                    .registers
+                    .entries()
+                    .into_iter()
+                    .take(area.height as usize - 2)
+                    .map(|(name, content)| {
+                        Line::from(vec![
+                            Span::styled(format!("\"{name} "), theme.ui(UiElement::DialogLabel)),
+                            Span::from(match content.kind {
+                                RegisterKind::Charwise => "c ",
+                                RegisterKind::Linewise => "l ",
+                                RegisterKind::Blockwise => "b ",
+                            }),
+                            Span::from(content.text.replace('\n', "^J")),
+                        ])
+                    })
+                    .collect();
+                let block = Dialog::create_block(theme).title("Registers");
+                terminal.render_widget(Paragraph::new(lines).block(block), area);
+            }
+            Dialog::SearchResults => {
+                let Some(grep) = &app.grep else {
+                    return;
+                };
+                let title = match grep.searched_files {
+                    Some(files) => format!(
+                        "{} matches of {} in {files} files",
+                        grep.matches.len(),
+                        grep.pattern
+                    ),
+                    None => format!(
+                        "{} matches of {}, searching...",
+                        grep.matches.len(),
+                        grep.pattern
+                    ),
+                };
+                let height = (area.height as usize).saturating_sub(2);
+                let to_skip = (grep.selected + 1).saturating_sub(height);
+                let lines: Vec<Line> = grep
+                    .matches
+                    .iter()
+                    .enumerate()
+                    .skip(to_skip)
+                    .take(height)
+                    .map(|(idx, grep_match)| {
                         let mut spans = vec![
-                            Span::from(window.resolve_title()),
-                            Span::from(" "),
-                            Span::from("[Attached: "),
-                            if let Some(p) = &window.attached_file_path {
-                                Span::from(p).fg(Color::Green)
-                            } else {
-                                Span::from("Not Attached").fg(Color::Red)
-                            },
-                            Span::from(" "),
-                            Span::from("Modified: "),
-                            if window.modified {
-                                Span::from("true").fg(Color::Red)
-                            } else {
-                                Span::from("false").fg(Color::Green)
-                            },
-                            Span::from("]"),
+                            Span::styled(grep_match.path.as_str(), theme.ui(UiElement::DialogPath)),
+                            Span::styled(
+                                format!(":{}: ", grep_match.line_index + 1),
+                                theme.ui(UiElement::DialogLabel),
+                            ),
+                            Span::from(grep_match.line.trim()),
                         ];
-
-                        if is_selcted {
+                        if idx == grep.selected {
                             spans = spans
                                 .into_iter()
-                                .map(|s| s.bg(COMMAND_MODE_BACKGROUND))
+                                .map(|s| styled(s, theme.ui(UiElement::DialogSelection)))
                                 .collect();
                         }
-
                         Line::from(spans)
                     })
-                    .collect::<Vec<Line>>();
+                    .collect();
+                let block = Dialog::create_block(theme).title(title);
+                terminal.render_widget(Paragraph::new(lines).block(block), area);
+            }
+            Dialog::FileFinder(finder) => {
+                let title = if finder.indexing_done {
+                    format!("Files {}/{}", finder.results.len(), finder.files.len())
+                } else {
+                    format!(
+                        "Files {}/{}, indexing...",
+                        finder.results.len(),
+                        finder.files.len()
+                    )
+                };
+                let height = (area.height as usize).saturating_sub(3);
+                let to_skip = (finder.selected + 1).saturating_sub(height);
+                let mut lines = vec![Line::from(format!("> {}", finder.query))];
+                lines.extend(
+                    finder
+                        .results
+                        .iter()
+                        .enumerate()
+                        .skip(to_skip)
+                        .take(height)
+                        .map(|(idx, result)| {
+                            let path = &finder.files[result.file_index];
+                            let positions = &result.fuzzy_match.positions;
+                            let mut spans: Vec<Span> = path
+                                .chars()
+                                .enumerate()
+                                .map(|(i, c)| {
+                                    let span = Span::from(c.to_string());
+                                    if positions.contains(&i) {
+                                        styled(span, theme.ui(UiElement::DialogMatch))
+                                    } else {
+                                        span
+                                    }
+                                })
+                                .collect();
+                            if idx == finder.selected {
+                                spans = spans
+                                    .into_iter()
+                                    .map(|s| styled(s, theme.ui(UiElement::DialogSelection)))
+                                    .collect();
+                            }
+                            Line::from(spans)
+                        }),
+                );
+                let cursor_x = finder
+                    .query
+                    .chars()
+                    .take(finder.char_idx)
+                    .map(|c| unicode_width::UnicodeWidthChar::width(c).unwrap_or(1))
+                    .sum::<usize>()
+                    + 3;
+                terminal.set_cursor(area.x + cursor_x as u16, area.y + 1);
+                let block = Dialog::create_block(theme).title(title);
+                terminal.render_widget(Paragraph::new(lines).block(block), area);
+            }
+            Dialog::Keymaps { scroll } => {
+                let lines: Vec<Line> = MapMode::ALL
+                    .into_iter()
+                    .flat_map(|mode| {
+                        app.keymaps
+                            .get(mode)
+                            .bindings()
+                            .iter()
+                            .map(move |(keys, binding)| {
+                                Line::from(vec![
+                                    Span::styled(
+                                        format!("{:<7} ", mode.name()),
+                                        theme.ui(UiElement::DialogDetail),
+                                    ),
+                                    Span::styled(
+                                        format!("{:<10} ", display_sequence(keys)),
+                                        theme.ui(UiElement::DialogLabel),
+                                    ),
+                                    Span::from(binding.name()),
+                                ])
+                            })
+                    })
+                    .skip(*scroll)
+                    .take((area.height as usize).saturating_sub(2))
+                    .collect();
+                let block = Dialog::create_block(theme).title("Keymaps");
                 terminal.render_widget(Paragraph::new(lines).block(block), area);
             }
             Dialog::Logs => {
                 let lines: Vec<Line> = app
                     .log
-                    .take_lines()
+                    .take_entries()
                     .take(area.height as usize - 2)
-                    .map(Line::from)
+                    .map(|(severity, line)| Line::styled(line, theme.log_style(severity)))
                     .collect();
-                let block = Dialog::create_block().title("Log");
+                let block = Dialog::create_block(theme).title("Log");
                 terminal.render_widget(Paragraph::new(lines).block(block), area);
             }
         }
     }
 }
+
+fn styled(mut span: Span<'_>, style: Style) -> Span<'_> {
+    span.patch_style(style);
+    span
+}
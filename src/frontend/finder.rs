@@ -0,0 +1,151 @@
+use std::{
+    path::Path,
+    sync::mpsc::{self, Receiver, TryRecvError},
+    thread,
+};
+
+use ignore::WalkBuilder;
+
+use super::fuzzy::{fuzzy_match, FuzzyMatch};
+
+/// How many paths the indexing thread collects before sending them
+const INDEX_BATCH_SIZE: usize = 256;
+
+/// A file that matches the query of the file finder.
+pub struct FinderResult {
+    pub file_index: usize,
+    pub fuzzy_match: FuzzyMatch,
+}
+
+/// The state of the fuzzy file finder, the files below the working directory are indexed in
+/// the background while it is open.
+pub struct FileFinder {
+    /// The indexed directory, the listed files are relative to it
+    root: String,
+    pub query: String,
+    pub char_idx: usize,
+    pub files: Vec<String>,
+    /// The files matching the query, best first
+    pub results: Vec<FinderResult>,
+    pub selected: usize,
+    pub indexing_done: bool,
+    receiver: Receiver<Vec<String>>,
+}
+
+impl FileFinder {
+    /// Starts indexing the files below `path`, skipping the ones that `.gitignore` ignores.
+    pub fn new(path: &str) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        let walker = WalkBuilder::new(path).require_git(false).build();
+        let prefix = format!("{}/", path.trim_end_matches('/'));
+        thread::spawn(move || {
+            let mut batch = Vec::new();
+            for entry in walker.flatten() {
+                if !entry.file_type().is_some_and(|t| t.is_file()) {
+                    continue;
+                }
+                let path = entry.path().display().to_string();
+                batch.push(path.strip_prefix(&prefix).unwrap_or(&path).to_string());
+                if batch.len() == INDEX_BATCH_SIZE
+                    && sender.send(std::mem::take(&mut batch)).is_err()
+                {
+                    return;
+                }
+            }
+            let _ = sender.send(batch);
+        });
+        FileFinder {
+            root: path.to_string(),
+            query: String::new(),
+            char_idx: 0,
+            files: Vec::new(),
+            results: Vec::new(),
+            selected: 0,
+            indexing_done: false,
+            receiver,
+        }
+    }
+
+    /// Takes the files indexed since the last call.
+    pub fn receive(&mut self) {
+        let mut received = false;
+        loop {
+            match self.receiver.try_recv() {
+                Ok(batch) => {
+                    self.files.extend(batch);
+                    received = true;
+                }
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => {
+                    self.indexing_done = true;
+                    break;
+                }
+            }
+        }
+        if received {
+            self.update_results(true);
+        }
+    }
+
+    /// Matches all files against the query again. Either the selected file stays selected or
+    /// the best match is selected.
+    pub fn update_results(&mut self, keep_selection: bool) {
+        let selected_file = self
+            .selected_path()
+            .filter(|_| keep_selection)
+            .map(str::to_string);
+        let mut results: Vec<FinderResult> = self
+            .files
+            .iter()
+            .enumerate()
+            .filter_map(|(file_index, path)| {
+                fuzzy_match(&self.query, path).map(|fuzzy_match| FinderResult {
+                    file_index,
+                    fuzzy_match,
+                })
+            })
+            .collect();
+        results.sort_by(|a, b| {
+            let (path_a, path_b) = (&self.files[a.file_index], &self.files[b.file_index]);
+            b.fuzzy_match
+                .score
+                .cmp(&a.fuzzy_match.score)
+                .then(path_a.len().cmp(&path_b.len()))
+                .then(path_a.cmp(path_b))
+        });
+        self.results = results;
+        self.selected = selected_file
+            .and_then(|selected| {
+                self.results
+                    .iter()
+                    .position(|r| self.files[r.file_index] == selected)
+            })
+            .unwrap_or(0);
+    }
+
+    pub fn selected_path(&self) -> Option<&str> {
+        self.results
+            .get(self.selected)
+            .map(|r| self.files[r.file_index].as_str())
+    }
+
+    /// Returns the path to open the selected file with.
+    pub fn selected_file(&self) -> Option<String> {
+        let path = self.selected_path()?;
+        if self.root == "." {
+            Some(path.to_string())
+        } else {
+            Some(Path::new(&self.root).join(path).display().to_string())
+        }
+    }
+
+    pub fn select_next(&mut self) {
+        if self.selected + 1 < self.results.len() {
+            self.selected += 1;
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+}
@@ -0,0 +1,131 @@
+/// The score of every matched char
+const SCORE_MATCH: i32 = 16;
+/// Added when a char matches right after the previous one
+const BONUS_CONSECUTIVE: i32 = 16;
+/// Added when a char matches at the start of the file name
+const BONUS_FILE_NAME: i32 = 12;
+/// Added when a char matches at the start of a directory name
+const BONUS_PATH_SEPARATOR: i32 = 10;
+/// Added when a char matches at the start of a word, after `_`, `-`, `.` or a space
+const BONUS_WORD: i32 = 8;
+/// Added when a char matches an uppercase letter after a lowercase one
+const BONUS_CAMEL_CASE: i32 = 7;
+/// Subtracted for every char skipped between two matched chars
+const PENALTY_GAP: i32 = 1;
+
+/// A candidate that matches a query, along with where the chars of the query are in it.
+pub struct FuzzyMatch {
+    pub score: i32,
+    /// The char indices in the candidate that matched, in order
+    pub positions: Vec<usize>,
+}
+
+fn bonus(candidate: &[char], j: usize, file_name_start: usize) -> i32 {
+    if j == file_name_start {
+        return BONUS_FILE_NAME;
+    }
+    let Some(previous) = j.checked_sub(1).map(|i| candidate[i]) else {
+        return BONUS_PATH_SEPARATOR;
+    };
+    match previous {
+        '/' | '\\' => BONUS_PATH_SEPARATOR,
+        '_' | '-' | '.' | ' ' => BONUS_WORD,
+        p if p.is_lowercase() && candidate[j].is_uppercase() => BONUS_CAMEL_CASE,
+        _ => 0,
+    }
+}
+
+/// Matches `query` against `candidate` if the chars of the query appear in it in order. Like
+/// search, case is ignored unless the query contains an uppercase letter. Matches at the
+/// start of names and runs of consecutive chars score higher, gaps lower.
+pub fn fuzzy_match(query: &str, candidate: &str) -> Option<FuzzyMatch> {
+    let ignore_case = !query.chars().any(char::is_uppercase);
+    let normalize = |c: char| {
+        if ignore_case {
+            c.to_lowercase().next().unwrap_or(c)
+        } else {
+            c
+        }
+    };
+    let query: Vec<char> = query.chars().map(normalize).collect();
+    let original: Vec<char> = candidate.chars().collect();
+    let chars: Vec<char> = original.iter().copied().map(normalize).collect();
+    if query.is_empty() {
+        return Some(FuzzyMatch {
+            score: 0,
+            positions: Vec::new(),
+        });
+    }
+
+    // Rule out candidates that don't contain the query before doing the expensive part
+    let mut remaining = query.iter().peekable();
+    for c in &chars {
+        if remaining.peek() == Some(&c) {
+            remaining.next();
+        }
+    }
+    if remaining.peek().is_some() {
+        return None;
+    }
+
+    let file_name_start = original
+        .iter()
+        .rposition(|c| *c == '/' || *c == '\\')
+        .map_or(0, |i| i + 1);
+    // scores[i][j] is the best score of matching query[..=i] with query[i] at chars[j],
+    // previous[i][j] the position of query[i - 1] for that score
+    let width = chars.len();
+    let mut scores = vec![None; query.len() * width];
+    let mut previous = vec![0; query.len() * width];
+    for (i, q) in query.iter().enumerate() {
+        // The best score of the previous row before j, with the gap penalty up to j applied
+        let mut best_before: Option<(i32, usize)> = None;
+        for j in 0..width {
+            if i > 0 && j > 0 {
+                if let Some(score) = scores[(i - 1) * width + j - 1] {
+                    let candidate = (score, j - 1);
+                    best_before = match best_before {
+                        Some((best, k)) if best - PENALTY_GAP >= score => {
+                            Some((best - PENALTY_GAP, k))
+                        }
+                        _ => Some(candidate),
+                    };
+                } else if let Some((best, k)) = best_before {
+                    best_before = Some((best - PENALTY_GAP, k));
+                }
+            }
+            if chars[j] != *q {
+                continue;
+            }
+            let own = SCORE_MATCH + bonus(&original, j, file_name_start);
+            if i == 0 {
+                scores[j] = Some(own);
+                continue;
+            }
+            let consecutive = (j > 0)
+                .then(|| scores[(i - 1) * width + j - 1])
+                .flatten()
+                .map(|score| (score + BONUS_CONSECUTIVE, j - 1));
+            let from = match (consecutive, best_before) {
+                (Some(c), Some(b)) if b.0 > c.0 => Some(b),
+                (Some(c), _) => Some(c),
+                (None, b) => b,
+            };
+            if let Some((score, k)) = from {
+                scores[i * width + j] = Some(score + own);
+                previous[i * width + j] = k;
+            }
+        }
+    }
+
+    let last_row = (query.len() - 1) * width;
+    let (mut j, score) = (0..width)
+        .filter_map(|j| scores[last_row + j].map(|score| (j, score)))
+        .max_by_key(|(_, score)| *score)?;
+    let mut positions = vec![0; query.len()];
+    for i in (0..query.len()).rev() {
+        positions[i] = j;
+        j = previous[i * width + j];
+    }
+    Some(FuzzyMatch { score, positions })
+}
@@ -0,0 +1,170 @@
+use std::{
+    fs,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, Receiver, TryRecvError},
+        Arc,
+    },
+    thread,
+};
+
+use ignore::WalkBuilder;
+use regex::Regex;
+
+/// A line of a file that matches the pattern of `:grep`.
+pub struct GrepMatch {
+    pub path: String,
+    /// The zero based line of the match
+    pub line_index: usize,
+    /// The char index of the match in its line
+    pub column: usize,
+    pub line: String,
+}
+
+enum GrepMessage {
+    Match(GrepMatch),
+    Done { searched_files: usize },
+}
+
+/// The results of `:grep`, which arrive from a background thread while they are shown.
+pub struct Grep {
+    pub pattern: String,
+    pub matches: Vec<GrepMatch>,
+    pub selected: usize,
+    /// The number of files searched once the search is finished
+    pub searched_files: Option<usize>,
+    receiver: Receiver<GrepMessage>,
+    /// Set when the `Grep` is dropped, the search checks it before every file
+    stopped: Arc<AtomicBool>,
+}
+
+impl Grep {
+    /// Searches the files below `path` for `regex` in a background thread, skipping the files
+    /// that `.gitignore` ignores. Dropping the `Grep` stops the search.
+    pub fn start(pattern: &str, regex: Regex, path: &str) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        let stopped = Arc::new(AtomicBool::new(false));
+        let walker = WalkBuilder::new(path).require_git(false).build();
+        let thread_stopped = Arc::clone(&stopped);
+        thread::spawn(move || {
+            let mut searched_files = 0;
+            for entry in walker.flatten() {
+                if thread_stopped.load(Ordering::Relaxed) {
+                    return;
+                }
+                if !entry.file_type().is_some_and(|t| t.is_file()) {
+                    continue;
+                }
+                // Binary files and files that aren't UTF-8 are skipped
+                let Ok(text) = fs::read_to_string(entry.path()) else {
+                    continue;
+                };
+                searched_files += 1;
+                let path = entry.path().display().to_string();
+                let path = path.strip_prefix("./").unwrap_or(&path);
+                for (line_index, line) in text.lines().enumerate() {
+                    let Some(m) = regex.find(line) else {
+                        continue;
+                    };
+                    let grep_match = GrepMatch {
+                        path: path.to_string(),
+                        line_index,
+                        column: line[..m.start()].chars().count(),
+                        line: line.to_string(),
+                    };
+                    if sender.send(GrepMessage::Match(grep_match)).is_err() {
+                        return;
+                    }
+                }
+            }
+            let _ = sender.send(GrepMessage::Done { searched_files });
+        });
+        Grep {
+            pattern: pattern.to_string(),
+            matches: Vec::new(),
+            selected: 0,
+            searched_files: None,
+            receiver,
+            stopped,
+        }
+    }
+
+    /// Takes the results that arrived since the last call.
+    pub fn receive(&mut self) {
+        loop {
+            match self.receiver.try_recv() {
+                Ok(GrepMessage::Match(grep_match)) => self.matches.push(grep_match),
+                Ok(GrepMessage::Done { searched_files }) => {
+                    self.searched_files = Some(searched_files)
+                }
+                Err(TryRecvError::Empty | TryRecvError::Disconnected) => return,
+            }
+        }
+    }
+
+    pub fn selected_match(&self) -> Option<&GrepMatch> {
+        self.matches.get(self.selected)
+    }
+
+    pub fn select_next(&mut self) {
+        if self.selected + 1 < self.matches.len() {
+            self.selected += 1;
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+}
+
+impl Drop for Grep {
+    fn drop(&mut self) {
+        self.stopped.store(true, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use regex::Regex;
+
+    use super::Grep;
+
+    fn finished(mut grep: Grep) -> Grep {
+        while grep.searched_files.is_none() {
+            grep.receive();
+        }
+        grep
+    }
+
+    #[test]
+    fn finds_matching_lines() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("a.txt"), "one\ntwo needle\nthree\n").unwrap();
+        fs::write(dir.path().join("b.txt"), "no match\n").unwrap();
+        fs::write(dir.path().join("c.bin"), [0xff, 0xfe, b'\n']).unwrap();
+        let path = dir.path().to_str().unwrap();
+        let grep = finished(Grep::start("needle", Regex::new("needle").unwrap(), path));
+        assert_eq!(grep.searched_files, Some(2));
+        assert_eq!(grep.matches.len(), 1);
+        let m = &grep.matches[0];
+        assert!(m.path.ends_with("a.txt"));
+        assert_eq!((m.line_index, m.column), (1, 4));
+        assert_eq!(m.line, "two needle");
+    }
+
+    #[test]
+    fn dropping_stops_the_search() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("a.txt"), "needle\n").unwrap();
+        let grep = Grep::start(
+            "needle",
+            Regex::new("needle").unwrap(),
+            dir.path().to_str().unwrap(),
+        );
+        let stopped = grep.stopped.clone();
+        drop(grep);
+        assert!(stopped.load(std::sync::atomic::Ordering::Relaxed));
+    }
+}
@@ -0,0 +1,197 @@
+use ropey::Rope;
+
+#[derive(Clone)]
+pub enum Edit {
+    Insert { char_idx: usize, text: String },
+    Remove { char_idx: usize, text: String },
+}
+
+impl Edit {
+    pub fn apply(&self, rope: &mut Rope) {
+        match self {
+            Edit::Insert { char_idx, text } => rope.insert(*char_idx, text),
+            Edit::Remove { char_idx, text } => {
+                rope.remove(*char_idx..*char_idx + text.chars().count())
+            }
+        }
+    }
+
+    pub fn invert(&self) -> Edit {
+        match self {
+            Edit::Insert { char_idx, text } => Edit::Remove {
+                char_idx: *char_idx,
+                text: text.clone(),
+            },
+            Edit::Remove { char_idx, text } => Edit::Insert {
+                char_idx: *char_idx,
+                text: text.clone(),
+            },
+        }
+    }
+
+    /// Returns where `position` is after this edit, or `None` if the edit removed it.
+    pub fn shift_position(&self, position: usize) -> Option<usize> {
+        match self {
+            Edit::Insert { char_idx, text } if position >= *char_idx => {
+                Some(position + text.chars().count())
+            }
+            Edit::Remove { char_idx, text } if position >= *char_idx => {
+                let len = text.chars().count();
+                if position >= char_idx + len {
+                    Some(position - len)
+                } else {
+                    None
+                }
+            }
+            _ => Some(position),
+        }
+    }
+
+    pub fn char_idx(&self) -> usize {
+        match self {
+            Edit::Insert { char_idx, .. } | Edit::Remove { char_idx, .. } => *char_idx,
+        }
+    }
+}
+
+struct Change {
+    edits: Vec<Edit>,
+}
+
+impl Change {
+    /// Where the change happened, which is where the cursor goes after undoing or redoing it.
+    fn position(&self) -> Option<usize> {
+        self.edits.iter().map(Edit::char_idx).min()
+    }
+}
+
+struct Node {
+    parent: usize,
+    last_child: Option<usize>,
+    change: Change,
+}
+
+/// An undo tree. Every node except the root holds one change, which is a group of edits
+/// that are undone and redone as a single step.
+pub struct History {
+    nodes: Vec<Node>,
+    current: usize,
+    open_change: Option<Change>,
+    /// How many `begin_change` calls are waiting for their `end_change`
+    open_depth: usize,
+    /// The node whose text was last written to or read from the file
+    saved: usize,
+}
+
+impl History {
+    pub fn new() -> Self {
+        History {
+            nodes: vec![Node {
+                parent: 0,
+                last_child: None,
+                change: Change { edits: Vec::new() },
+            }],
+            current: 0,
+            open_change: None,
+            open_depth: 0,
+            saved: 0,
+        }
+    }
+
+    /// Remembers the current text as the one in the file.
+    pub fn mark_saved(&mut self) {
+        self.close_change();
+        self.saved = self.current;
+    }
+
+    /// Returns whether the text is the one that was last marked as saved.
+    pub fn is_saved(&self) -> bool {
+        self.current == self.saved && self.open_edits().is_empty()
+    }
+
+    /// Starts grouping all following edits into one change until `end_change` is called.
+    /// Calls can be nested, the change ends with the outermost `end_change`.
+    pub fn begin_change(&mut self) {
+        if self.open_change.is_none() {
+            self.open_change = Some(Change { edits: Vec::new() });
+        }
+        self.open_depth += 1;
+    }
+
+    pub fn end_change(&mut self) {
+        self.open_depth = self.open_depth.saturating_sub(1);
+        if self.open_depth == 0 {
+            self.close_change();
+        }
+    }
+
+    fn close_change(&mut self) {
+        self.open_depth = 0;
+        if let Some(change) = self.open_change.take() {
+            self.commit(change);
+        }
+    }
+
+    /// Returns the edits recorded so far in the open change.
+    pub fn open_edits(&self) -> &[Edit] {
+        self.open_change
+            .as_ref()
+            .map(|change| change.edits.as_slice())
+            .unwrap_or(&[])
+    }
+
+    pub fn record(&mut self, edit: Edit) {
+        match &mut self.open_change {
+            Some(change) => change.edits.push(edit),
+            None => self.commit(Change { edits: vec![edit] }),
+        }
+    }
+
+    fn commit(&mut self, change: Change) {
+        if change.edits.is_empty() {
+            return;
+        }
+        let index = self.nodes.len();
+        self.nodes.push(Node {
+            parent: self.current,
+            last_child: None,
+            change,
+        });
+        self.nodes[self.current].last_child = Some(index);
+        self.current = index;
+    }
+
+    /// Reverts the current change and returns the position where it happened along with the
+    /// edits that reverted it.
+    pub fn undo(&mut self, rope: &mut Rope) -> Option<(usize, Vec<Edit>)> {
+        self.close_change();
+        if self.current == 0 {
+            return None;
+        }
+        let node = &self.nodes[self.current];
+        let edits: Vec<Edit> = node.change.edits.iter().rev().map(Edit::invert).collect();
+        for edit in &edits {
+            edit.apply(rope);
+        }
+        let position = node.change.position();
+        let parent = node.parent;
+        self.nodes[parent].last_child = Some(self.current);
+        self.current = parent;
+        position.map(|position| (position, edits))
+    }
+
+    /// Reapplies the most recently undone change and returns the position where it starts
+    /// along with its edits.
+    pub fn redo(&mut self, rope: &mut Rope) -> Option<(usize, Vec<Edit>)> {
+        self.close_change();
+        let child = self.nodes[self.current].last_child?;
+        let change = &self.nodes[child].change;
+        for edit in change.edits.iter() {
+            edit.apply(rope);
+        }
+        self.current = child;
+        change
+            .position()
+            .map(|position| (position, change.edits.clone()))
+    }
+}
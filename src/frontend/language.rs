@@ -1,98 +1,752 @@
-use std::process::Command;
+use std::{fs, io::ErrorKind, path::Path, process::Command, sync::OnceLock};
 
-use ratatui::style::Color;
-use tree_sitter_highlight::HighlightConfiguration;
+use regex::Regex;
+use toml::{Table, Value};
+use tree_sitter::{Query, QueryError};
 
-pub const HIGHLIGHTED_TOKENS: &[&str] = &["keyword", "string", "type"];
+use crate::{config, log::Log};
 
-pub const HIGHLIGHT_THEME: &[(&str, Color)] = &[
-    ("keyword", Color::Yellow),
-    ("string", Color::Green),
-    ("type", Color::Yellow),
+/// The capture names highlighting distinguishes. Captures of the queries that aren't listed
+/// count as the longest name whose parts they contain, `function.method.call` as
+/// `function.method`.
+pub const HIGHLIGHT_NAMES: &[&str] = &[
+    "attribute",
+    "boolean",
+    "character",
+    "character.special",
+    "comment",
+    "comment.documentation",
+    "constant",
+    "constant.builtin",
+    "constant.character",
+    "constant.numeric",
+    "constructor",
+    "delimiter",
+    "embedded",
+    "escape",
+    "function",
+    "function.builtin",
+    "function.macro",
+    "function.method",
+    "function.special",
+    "keyword",
+    "keyword.control",
+    "keyword.function",
+    "keyword.operator",
+    "keyword.return",
+    "label",
+    "module",
+    "namespace",
+    "number",
+    "operator",
+    "property",
+    "punctuation",
+    "punctuation.bracket",
+    "punctuation.delimiter",
+    "punctuation.special",
+    "string",
+    "string.escape",
+    "string.regexp",
+    "string.special",
+    "tag",
+    "text",
+    "text.emphasis",
+    "text.literal",
+    "text.reference",
+    "text.strong",
+    "text.title",
+    "text.uri",
+    "type",
+    "type.builtin",
+    "variable",
+    "variable.builtin",
+    "variable.parameter",
 ];
 
-pub fn get_highlight_color(token_type: &str) -> Option<Color> {
-    for (k, c) in HIGHLIGHT_THEME.iter() {
-        if *k == token_type {
-            return Some(*c);
+/// The definitions of the bundled languages, see the comment at the top of the file
+const BUNDLED_LANGUAGES: &str = include_str!("../../languages.toml");
+
+/// A tree-sitter grammar compiled into ted along with the queries that come with it. Grammars
+/// that build on others, like C++ on C, list their own queries before the ones they extend, as
+/// earlier patterns take precedence.
+struct Grammar {
+    name: &'static str,
+    language: fn() -> tree_sitter::Language,
+    highlight_queries: &'static [&'static str],
+    injection_queries: &'static [&'static str],
+    locals_queries: &'static [&'static str],
+}
+
+extern "C" {
+    fn tree_sitter_bash() -> tree_sitter::Language;
+    fn tree_sitter_regex() -> tree_sitter::Language;
+    fn tree_sitter_yaml() -> tree_sitter::Language;
+}
+
+fn bash_language() -> tree_sitter::Language {
+    unsafe { tree_sitter_bash() }
+}
+
+fn regex_language() -> tree_sitter::Language {
+    unsafe { tree_sitter_regex() }
+}
+
+fn yaml_language() -> tree_sitter::Language {
+    unsafe { tree_sitter_yaml() }
+}
+
+const GRAMMARS: &[Grammar] = &[
+    Grammar {
+        name: "rust",
+        language: tree_sitter_rust::language,
+        highlight_queries: &[tree_sitter_rust::HIGHLIGHT_QUERY],
+        injection_queries: &[
+            tree_sitter_rust::INJECTIONS_QUERY,
+            include_str!("../../queries/rust/injections.scm"),
+        ],
+        locals_queries: &[include_str!("../../queries/rust/locals.scm")],
+    },
+    Grammar {
+        name: "c",
+        language: tree_sitter_c::language,
+        highlight_queries: &[tree_sitter_c::HIGHLIGHT_QUERY],
+        injection_queries: &[],
+        locals_queries: &[],
+    },
+    Grammar {
+        name: "cpp",
+        language: tree_sitter_cpp::language,
+        highlight_queries: &[
+            tree_sitter_cpp::HIGHLIGHT_QUERY,
+            tree_sitter_c::HIGHLIGHT_QUERY,
+        ],
+        injection_queries: &[],
+        locals_queries: &[],
+    },
+    Grammar {
+        name: "go",
+        language: tree_sitter_go::language,
+        highlight_queries: &[tree_sitter_go::HIGHLIGHT_QUERY],
+        injection_queries: &[],
+        locals_queries: &[],
+    },
+    Grammar {
+        name: "python",
+        language: tree_sitter_python::language,
+        highlight_queries: &[tree_sitter_python::HIGHLIGHT_QUERY],
+        injection_queries: &[],
+        locals_queries: &[],
+    },
+    Grammar {
+        name: "javascript",
+        language: tree_sitter_javascript::language,
+        highlight_queries: &[
+            tree_sitter_javascript::JSX_HIGHLIGHT_QUERY,
+            tree_sitter_javascript::HIGHLIGHT_QUERY,
+        ],
+        injection_queries: &[tree_sitter_javascript::INJECTION_QUERY],
+        locals_queries: &[tree_sitter_javascript::LOCALS_QUERY],
+    },
+    Grammar {
+        name: "typescript",
+        language: tree_sitter_typescript::language_typescript,
+        highlight_queries: &[
+            tree_sitter_typescript::HIGHLIGHT_QUERY,
+            tree_sitter_javascript::HIGHLIGHT_QUERY,
+        ],
+        injection_queries: &[tree_sitter_javascript::INJECTION_QUERY],
+        locals_queries: &[
+            tree_sitter_typescript::LOCALS_QUERY,
+            tree_sitter_javascript::LOCALS_QUERY,
+        ],
+    },
+    Grammar {
+        name: "tsx",
+        language: tree_sitter_typescript::language_tsx,
+        highlight_queries: &[
+            tree_sitter_typescript::HIGHLIGHT_QUERY,
+            tree_sitter_javascript::JSX_HIGHLIGHT_QUERY,
+            tree_sitter_javascript::HIGHLIGHT_QUERY,
+        ],
+        injection_queries: &[tree_sitter_javascript::INJECTION_QUERY],
+        locals_queries: &[
+            tree_sitter_typescript::LOCALS_QUERY,
+            tree_sitter_javascript::LOCALS_QUERY,
+        ],
+    },
+    Grammar {
+        name: "json",
+        language: tree_sitter_json::language,
+        highlight_queries: &[tree_sitter_json::HIGHLIGHT_QUERY],
+        injection_queries: &[],
+        locals_queries: &[],
+    },
+    Grammar {
+        name: "toml",
+        language: tree_sitter_toml::language,
+        highlight_queries: &[tree_sitter_toml::HIGHLIGHT_QUERY],
+        injection_queries: &[],
+        locals_queries: &[],
+    },
+    // This version of the grammar parses emphasis, code spans and links itself, there is no
+    // separate inline grammar to inject
+    Grammar {
+        name: "markdown",
+        language: tree_sitter_md::language,
+        highlight_queries: &[tree_sitter_md::HIGHLIGHTS_QUERY],
+        injection_queries: &[include_str!("../../queries/markdown/injections.scm")],
+        locals_queries: &[],
+    },
+    Grammar {
+        name: "bash",
+        language: bash_language,
+        highlight_queries: &[include_str!("../../queries/bash/highlights.scm")],
+        injection_queries: &[],
+        locals_queries: &[],
+    },
+    Grammar {
+        name: "regex",
+        language: regex_language,
+        highlight_queries: &[include_str!("../../grammars/regex/queries/highlights.scm")],
+        injection_queries: &[],
+        locals_queries: &[],
+    },
+    Grammar {
+        name: "yaml",
+        language: yaml_language,
+        highlight_queries: &[include_str!("../../grammars/yaml/queries/highlights.scm")],
+        injection_queries: &[],
+        locals_queries: &[],
+    },
+];
+
+static REGISTRY: OnceLock<Vec<LanguageDefinition>> = OnceLock::new();
+
+/// The kinds of syntax nodes that text objects and `]f` style jumps work with.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum SyntaxObject {
+    Function,
+    /// Structs, enums, traits, impl blocks and the like
+    Class,
+    Parameter,
+    Comment,
+    Block,
+}
+
+impl SyntaxObject {
+    const ALL: [SyntaxObject; 5] = [
+        SyntaxObject::Function,
+        SyntaxObject::Class,
+        SyntaxObject::Parameter,
+        SyntaxObject::Comment,
+        SyntaxObject::Block,
+    ];
+
+    fn name(&self) -> &'static str {
+        match self {
+            SyntaxObject::Function => "function",
+            SyntaxObject::Class => "class",
+            SyntaxObject::Parameter => "parameter",
+            SyntaxObject::Comment => "comment",
+            SyntaxObject::Block => "block",
         }
     }
-    None
 }
 
+/// How a language is indented, applied to buffers of it before looking at their text.
+#[derive(Copy, Clone, Default)]
+pub struct Indent {
+    pub tabs: bool,
+    pub width: Option<usize>,
+}
+
+/// The compiled queries of a language.
+pub struct Queries {
+    pub highlights: Query,
+    /// Which of `HIGHLIGHT_NAMES` each capture of `highlights` stands for
+    pub highlight_names: Vec<Option<&'static str>>,
+    pub injections: Option<Query>,
+    /// Finds the scopes, definitions and references of local variables
+    pub locals: Option<Query>,
+}
+
+/// Everything ted knows about a language, read from `languages.toml`.
+struct LanguageDefinition {
+    name: String,
+    display_name: String,
+    grammar: &'static Grammar,
+    file_extensions: Vec<String>,
+    /// Names of files without a telling extension, like `Makefile`
+    file_names: Vec<String>,
+    /// Patterns matching the interpreter named in the `#!` line of a script
+    shebangs: Vec<Regex>,
+    comment_token: Option<String>,
+    block_comment: Option<(String, String)>,
+    indent: Option<Indent>,
+    /// The formatter and its arguments, `{path}` is replaced with the file to format
+    formatter: Option<Vec<String>>,
+    highlight_query: String,
+    injection_query: String,
+    locals_query: String,
+    text_objects: Vec<(SyntaxObject, Vec<String>)>,
+    /// Compiled the first time the language is highlighted, also when it is injected
+    queries: OnceLock<Result<Queries, String>>,
+}
+
+impl LanguageDefinition {
+    fn new(name: &str, grammar: &'static Grammar) -> Self {
+        LanguageDefinition {
+            name: name.to_string(),
+            display_name: name.to_string(),
+            grammar,
+            file_extensions: Vec::new(),
+            file_names: Vec::new(),
+            shebangs: Vec::new(),
+            comment_token: None,
+            block_comment: None,
+            indent: None,
+            formatter: None,
+            highlight_query: grammar.highlight_queries.join("\n"),
+            injection_query: grammar.injection_queries.join("\n"),
+            locals_query: grammar.locals_queries.join("\n"),
+            text_objects: Vec::new(),
+            queries: OnceLock::new(),
+        }
+    }
+
+    /// Sets the fields given in `table`, a `[[language]]` entry.
+    fn apply(&mut self, table: &Table) -> Result<(), String> {
+        for (key, value) in table {
+            match key.as_str() {
+                "name" => {}
+                "grammar" => {
+                    let grammar = grammar_by_name(&string(key, value)?)?;
+                    self.grammar = grammar;
+                    self.highlight_query = grammar.highlight_queries.join("\n");
+                    self.injection_query = grammar.injection_queries.join("\n");
+                    self.locals_query = grammar.locals_queries.join("\n");
+                }
+                "display_name" => self.display_name = string(key, value)?,
+                "file_extensions" => self.file_extensions = strings(key, value)?,
+                "file_names" => self.file_names = strings(key, value)?,
+                "shebangs" => {
+                    self.shebangs = strings(key, value)?
+                        .iter()
+                        .map(|pattern| {
+                            Regex::new(&format!("^(?:{pattern})$"))
+                                .map_err(|e| format!("Invalid shebang pattern {pattern}: {e}"))
+                        })
+                        .collect::<Result<_, _>>()?
+                }
+                "comment_token" => self.comment_token = Some(string(key, value)?),
+                "block_comment" => match strings(key, value)?.as_slice() {
+                    [start, end] => self.block_comment = Some((start.clone(), end.clone())),
+                    _ => return Err("block_comment should be a start and an end".to_string()),
+                },
+                "indent" => {
+                    let Value::Table(indent) = value else {
+                        return Err("indent should be a table".to_string());
+                    };
+                    self.indent = Some(Indent {
+                        tabs: indent.get("tabs").and_then(Value::as_bool).unwrap_or(false),
+                        width: indent
+                            .get("width")
+                            .and_then(Value::as_integer)
+                            .filter(|width| *width > 0)
+                            .map(|width| width as usize),
+                    });
+                }
+                "formatter" => {
+                    let formatter = strings(key, value)?;
+                    self.formatter = (!formatter.is_empty()).then_some(formatter);
+                }
+                "text_objects" => {
+                    let Value::Table(objects) = value else {
+                        return Err("text_objects should be a table".to_string());
+                    };
+                    for (name, kinds) in objects {
+                        let object = SyntaxObject::ALL
+                            .into_iter()
+                            .find(|object| object.name() == name)
+                            .ok_or_else(|| format!("Unknown text object {name}"))?;
+                        let kinds = strings(name, kinds)?;
+                        self.text_objects.retain(|(o, _)| *o != object);
+                        self.text_objects.push((object, kinds));
+                    }
+                }
+                _ => return Err(format!("Unknown field {key}")),
+            }
+        }
+        Ok(())
+    }
+
+    /// Replaces the queries with the ones in `directory` that can be compiled.
+    fn override_queries(&mut self, directory: &Path, errors: &mut Vec<String>) {
+        let mut read = |file: &str| match fs::read_to_string(directory.join(file)) {
+            Ok(query) => Some(query),
+            Err(e) if e.kind() == ErrorKind::NotFound => None,
+            Err(e) => {
+                errors.push(format!(
+                    "Could not read {}: {e}",
+                    directory.join(file).display()
+                ));
+                None
+            }
+        };
+        let (highlights, injections, locals) = (
+            read("highlights.scm"),
+            read("injections.scm"),
+            read("locals.scm"),
+        );
+        if highlights.is_none() && injections.is_none() && locals.is_none() {
+            return;
+        }
+        let highlight_query = highlights.unwrap_or_else(|| self.highlight_query.clone());
+        let injection_query = injections.unwrap_or_else(|| self.injection_query.clone());
+        let locals_query = locals.unwrap_or_else(|| self.locals_query.clone());
+        let language = (self.grammar.language)();
+        match Query::new(language, &highlight_query)
+            .and(Query::new(language, &injection_query))
+            .and(Query::new(language, &locals_query))
+        {
+            Ok(_) => {
+                self.highlight_query = highlight_query;
+                self.injection_query = injection_query;
+                self.locals_query = locals_query;
+            }
+            Err(e) => errors.push(format!("Invalid queries for {}: {}", self.name, e.message)),
+        }
+    }
+}
+
+fn grammar_by_name(name: &str) -> Result<&'static Grammar, String> {
+    GRAMMARS
+        .iter()
+        .find(|grammar| grammar.name == name)
+        .ok_or_else(|| format!("Unknown grammar {name}"))
+}
+
+fn string(key: &str, value: &Value) -> Result<String, String> {
+    value
+        .as_str()
+        .map(str::to_string)
+        .ok_or_else(|| format!("{key} should be a string"))
+}
+
+fn strings(key: &str, value: &Value) -> Result<Vec<String>, String> {
+    value
+        .as_array()
+        .and_then(|values| {
+            values
+                .iter()
+                .map(|value| value.as_str().map(str::to_string))
+                .collect()
+        })
+        .ok_or_else(|| format!("{key} should be a list of strings"))
+}
+
+/// Adds the `[[language]]` entries of `content` to `definitions`, or changes the ones with
+/// the same name.
+fn load_definitions(
+    content: &str,
+    definitions: &mut Vec<LanguageDefinition>,
+    errors: &mut Vec<String>,
+) {
+    let table = match content.parse::<Table>() {
+        Ok(table) => table,
+        Err(e) => {
+            errors.push(format!("Could not parse languages: {e}"));
+            return;
+        }
+    };
+    let Some(Value::Array(languages)) = table.get("language") else {
+        return;
+    };
+    for language in languages {
+        let Some(language) = language.as_table() else {
+            errors.push("Every [[language]] should be a table".to_string());
+            continue;
+        };
+        let Some(name) = language.get("name").and_then(Value::as_str) else {
+            errors.push("A language is missing its name".to_string());
+            continue;
+        };
+        let result = match definitions.iter_mut().find(|d| d.name == name) {
+            Some(definition) => definition.apply(language),
+            None => language
+                .get("grammar")
+                .and_then(Value::as_str)
+                .ok_or_else(|| "a new language needs a grammar".to_string())
+                .and_then(grammar_by_name)
+                .and_then(|grammar| {
+                    let mut definition = LanguageDefinition::new(name, grammar);
+                    definition.apply(language)?;
+                    definitions.push(definition);
+                    Ok(())
+                }),
+        };
+        if let Err(e) = result {
+            errors.push(format!("Language {name}: {e}"));
+        }
+    }
+}
+
+/// Reads the bundled languages and the ones in the config directory.
+fn load_registry(errors: &mut Vec<String>) -> Vec<LanguageDefinition> {
+    let mut definitions = Vec::new();
+    load_definitions(BUNDLED_LANGUAGES, &mut definitions, errors);
+    let Some(config_dir) = config::config_dir() else {
+        return definitions;
+    };
+    match fs::read_to_string(config_dir.join("languages.toml")) {
+        Ok(content) => load_definitions(&content, &mut definitions, errors),
+        Err(e) if e.kind() == ErrorKind::NotFound => {}
+        Err(e) => errors.push(format!("Could not read languages.toml: {e}")),
+    }
+    for definition in definitions.iter_mut() {
+        let directory = config_dir.join("queries").join(&definition.name);
+        definition.override_queries(&directory, errors);
+    }
+    definitions
+}
+
+/// Loads the languages at startup, so mistakes in the user's definitions end up in the log.
+pub fn init_registry(log: &mut Log) {
+    let mut errors = Vec::new();
+    let definitions = load_registry(&mut errors);
+    if REGISTRY.set(definitions).is_err() {
+        return;
+    }
+    for e in errors {
+        log.log(format!("Error: {e}"));
+    }
+}
+
+fn registry() -> &'static [LanguageDefinition] {
+    REGISTRY.get_or_init(|| load_registry(&mut Vec::new()))
+}
+
+/// A language of the registry.
 #[derive(Copy, Clone)]
-pub enum Language {
-    Rust,
-    C,
-    Go,
+pub struct Language(&'static LanguageDefinition);
+
+impl PartialEq for Language {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self.0, other.0)
+    }
 }
 
 impl Language {
+    /// Detects the language by the name of the file, checking whole file names like
+    /// `Cargo.lock` before extensions and preferring the longest matching extension.
     pub fn by_file_name(s: &str) -> Option<Language> {
-        if s.ends_with(".rs") {
-            Some(Language::Rust)
-        } else if s.ends_with(".c") {
-            Some(Language::C)
-        } else if s.ends_with(".go") {
-            Some(Language::Go)
-        } else {
-            None
+        let file_name = Path::new(s).file_name()?.to_str()?;
+        if let Some(definition) = registry()
+            .iter()
+            .find(|definition| definition.file_names.iter().any(|name| name == file_name))
+        {
+            return Some(Language(definition));
         }
+        registry()
+            .iter()
+            .flat_map(|definition| {
+                definition
+                    .file_extensions
+                    .iter()
+                    .filter(|extension| {
+                        file_name
+                            .strip_suffix(extension.as_str())
+                            .is_some_and(|stem| stem.len() > 1 && stem.ends_with('.'))
+                    })
+                    .map(move |extension| (extension.len(), definition))
+            })
+            .max_by_key(|(length, _)| *length)
+            .map(|(_, definition)| Language(definition))
     }
 
-    pub fn display_name(&self) -> &'static str {
-        match self {
-            Language::Rust => "Rust",
-            Language::C => "C",
-            Language::Go => "Go",
+    /// Finds the language an injection query names, like the language of a Markdown code
+    /// fence: `rust`, `Rust` and `rs` all name Rust.
+    pub fn by_injection_name(name: &str) -> Option<Language> {
+        let name = name.trim();
+        let registry = registry();
+        registry
+            .iter()
+            .find(|definition| definition.name.eq_ignore_ascii_case(name))
+            .or_else(|| {
+                registry
+                    .iter()
+                    .find(|definition| definition.display_name.eq_ignore_ascii_case(name))
+            })
+            .or_else(|| {
+                registry.iter().find(|definition| {
+                    definition
+                        .file_extensions
+                        .iter()
+                        .any(|extension| extension.eq_ignore_ascii_case(name))
+                })
+            })
+            .map(Language)
+    }
+
+    /// Detects the language of a script by its `#!` line.
+    pub fn by_shebang(first_line: &str) -> Option<Language> {
+        let command = first_line.strip_prefix("#!")?;
+        let mut words = command.split_whitespace();
+        let mut interpreter = Path::new(words.next()?).file_name()?.to_str()?;
+        if interpreter == "env" {
+            interpreter = words.find(|word| !word.starts_with('-'))?;
         }
+        registry()
+            .iter()
+            .find(|definition| {
+                definition
+                    .shebangs
+                    .iter()
+                    .any(|pattern| pattern.is_match(interpreter))
+            })
+            .map(Language)
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        &self.0.display_name
+    }
+
+    pub fn indent(&self) -> Option<Indent> {
+        self.0.indent
+    }
+
+    /// Returns the line comment token and the start and end of block comments.
+    pub fn comment_tokens(&self) -> (Option<&'static str>, Option<(&'static str, &'static str)>) {
+        (
+            self.0.comment_token.as_deref(),
+            self.0
+                .block_comment
+                .as_ref()
+                .map(|(start, end)| (start.as_str(), end.as_str())),
+        )
+    }
+
+    /// Returns the extension files of this language usually have.
+    pub fn file_extension(&self) -> Option<&'static str> {
+        self.0.file_extensions.first().map(String::as_str)
     }
 
     pub fn format_command(&self, p: &str) -> Option<Command> {
-        match self {
-            Language::C => None,
-            Language::Go => {
-                let mut cmd = Command::new("go");
-                cmd.arg("fmt");
-                cmd.arg(p);
-                Some(cmd)
-            }
-            Language::Rust => {
-                let mut cmd = Command::new("rustfmt");
-                cmd.arg(p);
-                Some(cmd)
-            }
+        let (program, args) = self.0.formatter.as_ref()?.split_first()?;
+        let mut cmd = Command::new(program);
+        if args.iter().any(|arg| arg.contains("{path}")) {
+            cmd.args(args.iter().map(|arg| arg.replace("{path}", p)));
+        } else {
+            cmd.args(args);
+            cmd.arg(p);
         }
+        Some(cmd)
     }
 
-    pub fn build_highlighter_config(&self) -> Option<HighlightConfiguration> {
-        let mut config = match self {
-            Language::Rust => {
-                let rust_language = tree_sitter_rust::language();
-                HighlightConfiguration::new(
-                    rust_language,
-                    tree_sitter_rust::HIGHLIGHT_QUERY,
-                    tree_sitter_rust::INJECTIONS_QUERY,
-                    "",
-                )
-                .ok()?
-            }
-            Language::C => {
-                let c_language = tree_sitter_c::language();
+    pub fn tree_sitter_language(&self) -> tree_sitter::Language {
+        (self.0.grammar.language)()
+    }
+
+    /// Returns the node kinds of the grammar that make up `object`. For parameters these are
+    /// the kinds of the lists the parameters and arguments are in.
+    pub fn syntax_node_kinds(&self, object: SyntaxObject) -> &'static [String] {
+        self.0
+            .text_objects
+            .iter()
+            .find(|(o, _)| *o == object)
+            .map_or(&[], |(_, kinds)| kinds.as_slice())
+    }
 
-                HighlightConfiguration::new(c_language, tree_sitter_c::HIGHLIGHT_QUERY, "", "")
-                    .ok()?
+    /// Returns the compiled queries of the language, or why they could not be compiled.
+    pub fn queries(&self) -> Result<&'static Queries, &'static str> {
+        self.0
+            .queries
+            .get_or_init(|| {
+                let language = self.tree_sitter_language();
+                let invalid =
+                    |e: QueryError| format!("Invalid queries for {}: {}", self.0.name, e.message);
+                let highlights = Query::new(language, &self.0.highlight_query).map_err(invalid)?;
+                let highlight_names = highlights
+                    .capture_names()
+                    .iter()
+                    .map(|capture| highlight_name(capture))
+                    .collect();
+                let optional_query = |query: &str| {
+                    if query.trim().is_empty() {
+                        Ok(None)
+                    } else {
+                        Query::new(language, query).map(Some).map_err(invalid)
+                    }
+                };
+                Ok(Queries {
+                    highlights,
+                    highlight_names,
+                    injections: optional_query(&self.0.injection_query)?,
+                    locals: optional_query(&self.0.locals_query)?,
+                })
+            })
+            .as_ref()
+            .map_err(String::as_str)
+    }
+}
+
+/// Returns the longest of `HIGHLIGHT_NAMES` whose parts all appear in `capture`.
+fn highlight_name(capture: &str) -> Option<&'static str> {
+    let parts: Vec<&str> = capture.split('.').collect();
+    HIGHLIGHT_NAMES
+        .iter()
+        .rev()
+        .filter(|name| name.split('.').all(|part| parts.contains(&part)))
+        .max_by_key(|name| name.split('.').count())
+        .copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::{load_definitions, Language, LanguageDefinition, BUNDLED_LANGUAGES};
+
+    fn bundled() -> Vec<LanguageDefinition> {
+        let mut errors = Vec::new();
+        let mut definitions = Vec::new();
+        load_definitions(BUNDLED_LANGUAGES, &mut definitions, &mut errors);
+        assert!(errors.is_empty(), "{errors:?}");
+        definitions
+    }
+
+    #[test]
+    fn bundled_queries_compile() {
+        for definition in bundled() {
+            let language = Language(Box::leak(Box::new(definition)));
+            if let Err(e) = language.queries() {
+                panic!("{e}");
             }
+        }
+    }
 
-            Language::Go => HighlightConfiguration::new(
-                tree_sitter_go::language(),
-                tree_sitter_go::HIGHLIGHT_QUERY,
-                "",
-                "",
-            )
-            .ok()?,
-        };
-        config.configure(HIGHLIGHTED_TOKENS);
-        Some(config)
+    #[test]
+    fn locals_query_can_be_overridden() {
+        let mut rust = bundled().into_iter().find(|d| d.name == "rust").unwrap();
+        assert!(rust.locals_query.contains("@local.scope"));
+        let directory = tempfile::tempdir().unwrap();
+
+        fs::write(
+            directory.path().join("locals.scm"),
+            "(not_a_node) @local.scope",
+        )
+        .unwrap();
+        let mut errors = Vec::new();
+        rust.override_queries(directory.path(), &mut errors);
+        assert_eq!(errors.len(), 1);
+        assert!(rust.locals_query.contains("(block)"));
+
+        fs::write(directory.path().join("locals.scm"), "(block) @local.scope").unwrap();
+        let mut errors = Vec::new();
+        rust.override_queries(directory.path(), &mut errors);
+        assert!(errors.is_empty(), "{errors:?}");
+        assert_eq!(rust.locals_query, "(block) @local.scope");
+        let language = Language(Box::leak(Box::new(rust)));
+        assert!(language.queries().unwrap().locals.is_some());
     }
 }
@@ -0,0 +1,409 @@
+use ratatui::layout::Rect;
+
+/// How a split arranges its two halves.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum SplitDirection {
+    /// Above each other, `:split`
+    Horizontal,
+    /// Side by side, `:vsplit`
+    Vertical,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum PaneDirection {
+    Left,
+    Down,
+    Up,
+    Right,
+}
+
+/// A part of the edit area that shows a window.
+pub struct Pane {
+    pub id: usize,
+    pub window_uuid: usize,
+}
+
+enum Node {
+    Pane(Pane),
+    Split {
+        direction: SplitDirection,
+        /// The share of the first half in percent
+        ratio: u16,
+        first: Box<Node>,
+        second: Box<Node>,
+    },
+}
+
+/// The smallest share a half of a split is resized to, in percent
+const MIN_RATIO: u16 = 10;
+
+/// Splits `area` in two along `direction`, giving the first half `ratio` percent.
+fn split_rect(area: Rect, direction: SplitDirection, ratio: u16) -> (Rect, Rect) {
+    match direction {
+        SplitDirection::Horizontal => {
+            let height = (area.height as u32 * ratio as u32 / 100) as u16;
+            let first = Rect { height, ..area };
+            let second = Rect {
+                y: area.y + height,
+                height: area.height - height,
+                ..area
+            };
+            (first, second)
+        }
+        SplitDirection::Vertical => {
+            let width = (area.width as u32 * ratio as u32 / 100) as u16;
+            let first = Rect { width, ..area };
+            let second = Rect {
+                x: area.x + width,
+                width: area.width - width,
+                ..area
+            };
+            (first, second)
+        }
+    }
+}
+
+impl Node {
+    fn contains(&self, id: usize) -> bool {
+        match self {
+            Node::Pane(pane) => pane.id == id,
+            Node::Split { first, second, .. } => first.contains(id) || second.contains(id),
+        }
+    }
+
+    fn find_pane(&self, id: usize) -> Option<&Pane> {
+        match self {
+            Node::Pane(pane) => (pane.id == id).then_some(pane),
+            Node::Split { first, second, .. } => {
+                first.find_pane(id).or_else(|| second.find_pane(id))
+            }
+        }
+    }
+
+    fn first_pane(&self) -> &Pane {
+        match self {
+            Node::Pane(pane) => pane,
+            Node::Split { first, .. } => first.first_pane(),
+        }
+    }
+
+    fn panes<'a>(&'a self, area: Rect, panes: &mut Vec<(Rect, &'a Pane)>) {
+        match self {
+            Node::Pane(pane) => panes.push((area, pane)),
+            Node::Split {
+                direction,
+                ratio,
+                first,
+                second,
+            } => {
+                let (first_area, second_area) = split_rect(area, *direction, *ratio);
+                first.panes(first_area, panes);
+                second.panes(second_area, panes);
+            }
+        }
+    }
+
+    fn panes_mut<'a>(&'a mut self, panes: &mut Vec<&'a mut Pane>) {
+        match self {
+            Node::Pane(pane) => panes.push(pane),
+            Node::Split { first, second, .. } => {
+                first.panes_mut(panes);
+                second.panes_mut(panes);
+            }
+        }
+    }
+
+    /// Replaces the node of pane `id` with the result of `f`.
+    fn replace_pane(self, id: usize, f: &mut Option<impl FnOnce(Pane) -> Node>) -> Node {
+        match self {
+            Node::Pane(pane) if pane.id == id => match f.take() {
+                Some(f) => f(pane),
+                None => Node::Pane(pane),
+            },
+            Node::Pane(pane) => Node::Pane(pane),
+            Node::Split {
+                direction,
+                ratio,
+                first,
+                second,
+            } => Node::Split {
+                direction,
+                ratio,
+                first: Box::new(first.replace_pane(id, f)),
+                second: Box::new(second.replace_pane(id, f)),
+            },
+        }
+    }
+
+    fn into_pane(self, id: usize) -> Option<Pane> {
+        match self {
+            Node::Pane(pane) => (pane.id == id).then_some(pane),
+            Node::Split { first, second, .. } => {
+                first.into_pane(id).or_else(|| second.into_pane(id))
+            }
+        }
+    }
+
+    /// Removes pane `id`, its sibling takes the place of their split.
+    fn remove_pane(self, id: usize) -> Node {
+        match self {
+            Node::Split { first, second, .. } if matches!(&*first, Node::Pane(p) if p.id == id) => {
+                *second
+            }
+            Node::Split { first, second, .. } if matches!(&*second, Node::Pane(p) if p.id == id) => {
+                *first
+            }
+            Node::Split {
+                direction,
+                ratio,
+                first,
+                second,
+            } => Node::Split {
+                direction,
+                ratio,
+                first: Box::new(first.remove_pane(id)),
+                second: Box::new(second.remove_pane(id)),
+            },
+            node => node,
+        }
+    }
+
+    /// Returns how many panes share the space along `direction`.
+    fn panes_along(&self, along: SplitDirection) -> u16 {
+        match self {
+            Node::Split {
+                direction,
+                first,
+                second,
+                ..
+            } if *direction == along => first.panes_along(along) + second.panes_along(along),
+            Node::Split { first, second, .. } => {
+                first.panes_along(along).max(second.panes_along(along))
+            }
+            Node::Pane(_) => 1,
+        }
+    }
+
+    fn equalize(&mut self) {
+        if let Node::Split {
+            direction,
+            ratio,
+            first,
+            second,
+        } = self
+        {
+            let first_panes = first.panes_along(*direction);
+            let second_panes = second.panes_along(*direction);
+            *ratio = first_panes * 100 / (first_panes + second_panes);
+            first.equalize();
+            second.equalize();
+        }
+    }
+
+    /// Grows pane `id` by `delta` cells along `along`, by moving the border of the innermost
+    /// split in that direction. Returns whether there was such a split.
+    fn resize(&mut self, area: Rect, id: usize, along: SplitDirection, delta: i32) -> bool {
+        let Node::Split {
+            direction,
+            ratio,
+            first,
+            second,
+        } = self
+        else {
+            return false;
+        };
+        let (first_area, second_area) = split_rect(area, *direction, *ratio);
+        let in_first = first.contains(id);
+        let resized = if in_first {
+            first.resize(first_area, id, along, delta)
+        } else {
+            second.resize(second_area, id, along, delta)
+        };
+        if resized || *direction != along || !(in_first || second.contains(id)) {
+            return resized;
+        }
+        let size = match direction {
+            SplitDirection::Horizontal => area.height,
+            SplitDirection::Vertical => area.width,
+        }
+        .max(1) as i32;
+        // Round away from zero so that a single cell moves the border too
+        let percent = delta.signum() * ((delta.abs() * 100 + size - 1) / size);
+        let delta = if in_first { percent } else { -percent };
+        *ratio = (*ratio as i32 + delta).clamp(MIN_RATIO as i32, 100 - MIN_RATIO as i32) as u16;
+        true
+    }
+}
+
+/// The panes of the edit area as a tree of splits.
+pub struct PaneLayout {
+    root: Node,
+    pub focused: usize,
+    next_id: usize,
+    /// The edit area of the last render, used to find neighbouring panes and resize them
+    pub area: Rect,
+}
+
+impl PaneLayout {
+    pub fn new(window_uuid: usize) -> Self {
+        PaneLayout {
+            root: Node::Pane(Pane { id: 0, window_uuid }),
+            focused: 0,
+            next_id: 1,
+            area: Rect::new(0, 0, 80, 24),
+        }
+    }
+
+    /// Takes the tree out to rebuild it, leaving a placeholder.
+    fn take_root(&mut self) -> Node {
+        let placeholder = Node::Pane(Pane {
+            id: usize::MAX,
+            window_uuid: 0,
+        });
+        std::mem::replace(&mut self.root, placeholder)
+    }
+
+    /// Returns the panes along with their part of `area`.
+    pub fn panes(&self, area: Rect) -> Vec<(Rect, &Pane)> {
+        let mut panes = Vec::new();
+        self.root.panes(area, &mut panes);
+        panes
+    }
+
+    pub fn panes_mut(&mut self) -> Vec<&mut Pane> {
+        let mut panes = Vec::new();
+        self.root.panes_mut(&mut panes);
+        panes
+    }
+
+    pub fn pane_count(&self) -> usize {
+        self.panes(self.area).len()
+    }
+
+    pub fn pane_mut(&mut self, id: usize) -> Option<&mut Pane> {
+        self.panes_mut().into_iter().find(|pane| pane.id == id)
+    }
+
+    pub fn focused_pane(&self) -> &Pane {
+        self.root
+            .find_pane(self.focused)
+            .expect("The focused pane is in the layout")
+    }
+
+    pub fn focused_pane_mut(&mut self) -> &mut Pane {
+        let focused = self.focused;
+        self.pane_mut(focused)
+            .expect("The focused pane is in the layout")
+    }
+
+    /// Splits the focused pane in two, the new half above or to the left of it gets the focus.
+    pub fn split(&mut self, direction: SplitDirection, window_uuid: usize) {
+        let id = self.next_id;
+        self.next_id += 1;
+        let root = self.take_root();
+        let mut split = Some(|pane| Node::Split {
+            direction,
+            ratio: 50,
+            first: Box::new(Node::Pane(Pane { id, window_uuid })),
+            second: Box::new(Node::Pane(pane)),
+        });
+        self.root = root.replace_pane(self.focused, &mut split);
+        self.focused = id;
+    }
+
+    /// Closes the focused pane and focuses the one that takes its space. The last pane can't
+    /// be closed.
+    pub fn close_focused(&mut self) -> bool {
+        if self.pane_count() == 1 {
+            return false;
+        }
+        let area = self.area;
+        let position = self
+            .panes(area)
+            .into_iter()
+            .find(|(_, pane)| pane.id == self.focused)
+            .map(|(rect, _)| (rect.x, rect.y));
+        let root = self.take_root();
+        self.root = root.remove_pane(self.focused);
+        // Focus the pane that now covers the top left corner of the closed one
+        self.focused = position
+            .and_then(|(x, y)| {
+                self.panes(area)
+                    .into_iter()
+                    .find(|(rect, _)| {
+                        (rect.x..rect.x + rect.width.max(1)).contains(&x)
+                            && (rect.y..rect.y + rect.height.max(1)).contains(&y)
+                    })
+                    .map(|(_, pane)| pane.id)
+            })
+            .unwrap_or_else(|| self.root.first_pane().id);
+        true
+    }
+
+    /// Closes all panes but the focused one.
+    pub fn only(&mut self) {
+        let root = self.take_root();
+        self.root = Node::Pane(
+            root.into_pane(self.focused)
+                .expect("The focused pane is in the layout"),
+        );
+    }
+
+    pub fn equalize(&mut self) {
+        self.root.equalize();
+    }
+
+    /// Grows the focused pane by `delta` cells, or shrinks it if `delta` is negative.
+    pub fn resize_focused(&mut self, along: SplitDirection, delta: i32) {
+        let area = self.area;
+        let focused = self.focused;
+        self.root.resize(area, focused, along, delta);
+    }
+
+    /// Returns the pane next to the focused one in `direction`, the one overlapping the most
+    /// if there are several and the top or left one of those.
+    pub fn neighbour(&self, direction: PaneDirection) -> Option<usize> {
+        let panes = self.panes(self.area);
+        let (current, _) = panes.iter().find(|(_, pane)| pane.id == self.focused)?;
+        let overlap = |a: u16, a_len: u16, b: u16, b_len: u16| {
+            (a + a_len).min(b + b_len).saturating_sub(a.max(b))
+        };
+        panes
+            .iter()
+            .filter(|(_, pane)| pane.id != self.focused)
+            .filter_map(|(rect, pane)| {
+                let (adjacent, overlapping) = match direction {
+                    PaneDirection::Left => (
+                        rect.x + rect.width == current.x,
+                        overlap(rect.y, rect.height, current.y, current.height),
+                    ),
+                    PaneDirection::Right => (
+                        current.x + current.width == rect.x,
+                        overlap(rect.y, rect.height, current.y, current.height),
+                    ),
+                    PaneDirection::Up => (
+                        rect.y + rect.height == current.y,
+                        overlap(rect.x, rect.width, current.x, current.width),
+                    ),
+                    PaneDirection::Down => (
+                        current.y + current.height == rect.y,
+                        overlap(rect.x, rect.width, current.x, current.width),
+                    ),
+                };
+                (adjacent && overlapping > 0).then_some((overlapping, pane.id))
+            })
+            .rev()
+            .max_by_key(|(overlapping, _)| *overlapping)
+            .map(|(_, id)| id)
+    }
+
+    /// Returns the pane after the focused one, going around to the first.
+    pub fn next(&self) -> usize {
+        let panes = self.panes(self.area);
+        let index = panes
+            .iter()
+            .position(|(_, pane)| pane.id == self.focused)
+            .unwrap_or(0);
+        panes[(index + 1) % panes.len()].1.id
+    }
+}
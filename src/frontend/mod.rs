@@ -8,37 +8,38 @@ use crossterm::{
 use ratatui::{
     layout::Rect,
     prelude::{Constraint, CrosstermBackend, Direction, Layout},
-    style::{Color, Stylize},
     text::{Line, Span},
     widgets::Paragraph,
     Terminal,
 };
-use ropey::Rope;
-use std::{
-    collections::HashMap,
-    env,
-    error::Error,
-    fs::File,
-    io::{stderr, BufRead, BufReader},
-    sync::mpsc::{self, Sender, TryRecvError},
-    thread,
-    time::Duration,
-};
-use tree_sitter_highlight::{HighlightEvent, Highlighter};
+use std::{env, error::Error, io::stderr, rc::Rc, time::Duration};
 
 use self::{
-    app::{App, Mode},
-    window::{HighlightData, HighlightJob, HighlightJobResult},
+    app::{App, Mode, VisualKind},
+    dialog::Dialog,
+    theme::{Theme, UiElement},
+    window::Selection,
 };
-use crate::log::Log;
+use crate::keys::keymap::display_sequence;
 
 pub mod app;
+pub mod buffer;
 pub mod dialog;
+pub mod finder;
+pub mod fuzzy;
+pub mod grep;
+pub mod history;
 pub mod language;
+pub mod layout;
+pub mod options;
+pub mod picker;
+pub mod register;
+pub mod search;
+pub mod syntax;
+pub mod tab;
+pub mod theme;
 pub mod window;
 
-const COMMAND_MODE_BACKGROUND: Color = Color::Rgb(77, 77, 77);
-
 fn initialize_panic_hook() {
     let original_hook = std::panic::take_hook();
     std::panic::set_hook(Box::new(move |panic_info| {
@@ -48,6 +49,60 @@ fn initialize_panic_hook() {
     }));
 }
 
+fn render_command_line(
+    frame: &mut ratatui::Frame<'_>,
+    rect: Rect,
+    theme: &Theme,
+    prompt: char,
+    buffer: &str,
+    char_idx: usize,
+) {
+    let cursor_x = buffer
+        .chars()
+        .take(char_idx)
+        .map(|c| unicode_width::UnicodeWidthChar::width(c).unwrap_or(1))
+        .sum::<usize>()
+        + 1;
+    frame.set_cursor(rect.x + cursor_x as u16, rect.y);
+    frame.render_widget(
+        Paragraph::new(Line::from(format!("{prompt}{buffer}")))
+            .style(theme.ui(UiElement::CommandLine)),
+        rect,
+    );
+}
+
+/// Shows the tabs with the title of the focused window of each, `+` marks tabs showing
+/// unsaved changes.
+fn render_tab_bar(frame: &mut ratatui::Frame<'_>, rect: Rect, app: &App) {
+    let mut spans = Vec::new();
+    for (i, tab) in app.tabs.iter().enumerate() {
+        let windows: Vec<_> = tab
+            .panes
+            .panes(tab.panes.area)
+            .into_iter()
+            .filter_map(|(_, pane)| app.edit_windows.iter().find(|w| w.uuid == pane.window_uuid))
+            .collect();
+        let focused = if i == app.selected_tab {
+            app.selected_window()
+        } else {
+            let uuid = tab.panes.focused_pane().window_uuid;
+            app.edit_windows.iter().find(|w| w.uuid == uuid)
+        };
+        let title = focused.map_or("[No window]".to_string(), |w| w.resolve_title());
+        let modified = windows.iter().any(|w| w.buffer().modified);
+        let label = format!(" {} {title}{} ", i + 1, if modified { "+" } else { "" });
+        spans.push(if i == app.selected_tab {
+            Span::styled(label, app.theme.ui(UiElement::SelectedTab))
+        } else {
+            Span::from(label)
+        });
+    }
+    frame.render_widget(
+        Paragraph::new(Line::from(spans)).style(app.theme.ui(UiElement::TabBar)),
+        rect,
+    );
+}
+
 fn centered_rect(r: Rect, percent_x: u16, percent_y: u16) -> Rect {
     let popup_layout = Layout::default()
         .direction(Direction::Vertical)
@@ -78,151 +133,49 @@ pub fn run() -> Result<(), Box<dyn Error>> {
     let mut terminal = Terminal::new(CrosstermBackend::new(stderr()))?;
     terminal.clear()?;
 
-    let (send_hl_job, recv_hl_job) = mpsc::channel::<HighlightJob>();
-    let (send_hl_job_result, recv_hl_job_result) = mpsc::channel::<HighlightJobResult>();
-
-    thread::spawn(move || {
-        let mut window_uuid_to_cancel_sender = HashMap::<usize, Sender<()>>::new();
-        let send_hl_job_result = send_hl_job_result;
-        loop {
-            let highlight_job = recv_hl_job.recv();
-            if highlight_job.is_err() {
-                return;
-            }
-            let highlight_job = highlight_job.unwrap();
-            if let Some(cancel_sender) =
-                window_uuid_to_cancel_sender.remove(&highlight_job.window_uuid)
-            {
-                let _ = cancel_sender.send(());
-            }
-            let send_hl_job_result = send_hl_job_result.clone();
-            let (cancel_sender, cancel_recv) = mpsc::channel::<()>();
-            window_uuid_to_cancel_sender.insert(highlight_job.window_uuid, cancel_sender);
-            thread::spawn(move || {
-                let mut highlighter = Highlighter::new();
-                let text = highlight_job.text.to_string();
-                let highlight_config = highlight_job
-                    .language
-                    .build_highlighter_config()
-                    .expect("Could not build job hl config");
-                let mut v: Vec<(usize, std::ops::Range<usize>, &str)> = Vec::new();
-                let highlights = highlighter
-                    .highlight(&highlight_config, text.as_bytes(), None, |_| None)
-                    .unwrap();
-
-                let mut last_token_type = None;
-                for event in highlights {
-                    match cancel_recv.try_recv() {
-                        Ok(_) => return,
-                        Err(TryRecvError::Disconnected) => return,
-                        _ => match event.unwrap() {
-                            HighlightEvent::Source { start, end } => {
-                                if let Some(last_token_type) = last_token_type {
-                                    let elem = (start, (start..end), last_token_type);
-                                    v.push(elem);
-                                }
-                            }
-                            HighlightEvent::HighlightStart(tree_sitter_highlight::Highlight(
-                                token_index,
-                            )) => {
-                                last_token_type = Some(
-                                    crate::frontend::language::HIGHLIGHTED_TOKENS[token_index],
-                                );
-                            }
-                            HighlightEvent::HighlightEnd => {
-                                last_token_type = None;
-                            }
-                        },
-                    }
-                }
-                v.sort_by(|(key1, ..), (key2, ..)| key1.cmp(key2));
-                send_hl_job_result
-                    .send(HighlightJobResult {
-                        window_uuid: highlight_job.window_uuid,
-                        highlights: v,
-                    })
-                    .expect("Could not send job result");
-            });
-        }
-    });
-
-    let mut app = App {
-        uuid_counter: 0,
-        edit_windows: Vec::new(),
-        selected_window: 0,
-        log: Log::new(),
-        current_mode: Mode::Normal,
-        highlight_job_queue: send_hl_job,
-    };
+    let mut app = App::new();
+    crate::config::load(&mut app);
+    language::init_registry(&mut app.log);
 
     let mut args = env::args();
     if let Some(path) = args.nth(1) {
-        let mut x = |rope: Rope| {
-            let window_index = app.create_empty_window();
-            let window = &mut app.edit_windows[window_index];
-            window.text = rope;
-            window.attached_file_path = Some(path.clone());
-            if let Some(lang) = window.try_detect_langauge() {
-                app.log
-                    .log(format!("[STARTUP] Detected {}", lang.display_name()));
-            } else {
-                app.log.log("[STARTUP] Couldn't detect language");
-            }
-            app.queue_selected_window_highlight_refresh();
-        };
-
-        match File::open(&path) {
-            Ok(f) => {
-                let mut reader = BufReader::new(f);
-                let mut text = String::new();
-                let mut line_buf = String::new();
-                let to_log = loop {
-                    line_buf.clear();
-                    let res = reader.read_line(&mut line_buf);
-                    match res {
-                        Ok(0) => break None,
-                        Ok(_) => {
-                            let replaced = line_buf.replace('\t', "    ");
-                            text.push_str(replaced.as_str());
-                        }
-                        Err(e) => {
-                            break Some(format!(
-                                "[STARTUP] Could not continue reading {path} due to {:?}",
-                                e
-                            ));
-                        }
-                    }
-                };
-                let rope = Rope::from_str(text.as_str());
-                x(rope);
-
-                if let Some(error_message) = to_log {
-                    app.log.log(error_message);
-                } else {
-                    app.selected_window = app.edit_windows.len() - 1;
-                    app.log.log(format!("[STARTUP] Successfully opened {path}"));
-                }
+        match app.open_file(&path) {
+            Ok(window_index) => {
+                let file_format = app.edit_windows[window_index].buffer().file_format;
+                app.log.log(format!(
+                    "[STARTUP] Successfully opened {path} [{file_format}]"
+                ));
             }
             Err(e) => {
-                x(Rope::new());
+                let window_index = app.create_empty_window();
+                app.edit_windows[window_index]
+                    .buffer_mut()
+                    .attached_file_path = Some(path.clone());
+                app.selected_window = window_index;
                 app.log.log(format!(
                     "[STARTUP] Could not open {path} due to {:?} -> created empty window",
                     e
                 ));
             }
         }
+        if let Some(lang) = app.detect_language(app.selected_window) {
+            let message = format!("[STARTUP] Detected {}", lang.display_name());
+            app.log.log(message);
+        } else {
+            app.log.log("[STARTUP] Couldn't detect language");
+        }
     }
 
     loop {
-        let hl_job_result = recv_hl_job_result.try_recv();
-
-        if let Ok(hl_job_result) = hl_job_result {
-            for w in app.edit_windows.iter_mut() {
-                if hl_job_result.window_uuid == w.uuid {
-                    w.highlight_data = Some(HighlightData::new(hl_job_result.highlights));
-                    break;
-                }
-            }
+        app.mark_selected_window_used();
+        if let Some(grep) = &mut app.grep {
+            grep.receive();
+        }
+        if let Mode::Dialog {
+            which_one: Dialog::FileFinder(finder),
+        } = &mut app.current_mode
+        {
+            finder.receive();
         }
 
         terminal.draw(|frame| {
@@ -235,8 +188,18 @@ pub fn run() -> Result<(), Box<dyn Error>> {
                 ])
                 .split(frame.size());
 
+            let mut edit_area = layout[0];
+            if app.tabs.len() > 1 {
+                let tab_layout = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([Constraint::Length(1), Constraint::Min(0)])
+                    .split(edit_area);
+                render_tab_bar(frame, tab_layout[0], &app);
+                edit_area = tab_layout[1];
+            }
+
+            let mut match_info = String::new();
             if app.edit_windows.is_empty() {
-                let edit_area = layout[0];
                 let center_of_edit_area = centered_rect(edit_area, 50, 50);
                 frame.render_widget(
                     Paragraph::new(vec![
@@ -252,11 +215,82 @@ pub fn run() -> Result<(), Box<dyn Error>> {
                     app.selected_window = app.edit_windows.len() - 1;
                 }
 
-                let highlight_line_number = !matches!(app.current_mode, Mode::Command { .. });
+                let highlight_line_number = !matches!(
+                    app.current_mode,
+                    Mode::Command { .. } | Mode::Search { .. } | Mode::ConfirmSubstitute { .. }
+                );
+                let search_pattern = app.search_pattern().map(str::to_string);
+
+                let confirm_match = match &app.current_mode {
+                    Mode::ConfirmSubstitute { substitution } => substitution.current_match(),
+                    _ => None,
+                };
+                let visual_kind = match app.current_mode {
+                    Mode::Visual { kind } => Some(kind),
+                    _ => None,
+                };
+
+                app.sync_panes();
+                app.tab_mut().panes.area = edit_area;
+                let panes: Vec<(Rect, usize, usize)> = app
+                    .tab()
+                    .panes
+                    .panes(edit_area)
+                    .into_iter()
+                    .map(|(rect, pane)| (rect, pane.id, pane.window_uuid))
+                    .collect();
+                let focused = app.tab().panes.focused;
+                let options = app.options.clone();
+                let theme = Rc::clone(&app.theme);
+                let mut focused_rect = edit_area;
+                for (rect, id, uuid) in panes.iter().copied() {
+                    if id == focused {
+                        focused_rect = rect;
+                        continue;
+                    }
+                    let Some(window) = app.edit_windows.iter_mut().find(|w| w.uuid == uuid) else {
+                        continue;
+                    };
+                    window.follow_buffer();
+                    window.render(
+                        frame,
+                        rect,
+                        &options,
+                        &theme,
+                        false,
+                        None,
+                        search_pattern.as_deref(),
+                        false,
+                    );
+                }
 
                 if let Some(sw) = app.selected_window_mut() {
-                    sw.render(frame, layout[0], highlight_line_number);
-                    sw.render_cursor(frame, layout[0]);
+                    sw.follow_buffer();
+                    let selection = match confirm_match {
+                        Some(range) => Some(Selection {
+                            kind: VisualKind::Char,
+                            ranges: vec![range],
+                        }),
+                        None => visual_kind.map(|kind| sw.selection(kind)),
+                    };
+                    sw.render(
+                        frame,
+                        focused_rect,
+                        &options,
+                        &theme,
+                        highlight_line_number,
+                        selection.as_ref(),
+                        search_pattern.as_deref(),
+                        panes.len() > 1,
+                    );
+                    sw.render_cursor(frame, focused_rect, &options);
+                    if search_pattern.is_some() {
+                        if let Some(matches) = &sw.search_matches {
+                            if let Some(i) = matches.index_at(sw.cursor_char_index) {
+                                match_info = format!("match {}/{}", i + 1, matches.ranges.len());
+                            }
+                        }
+                    }
                 }
             }
 
@@ -269,10 +303,27 @@ pub fn run() -> Result<(), Box<dyn Error>> {
                 }
                 Mode::Command { buffer, char_idx } => {
                     is_command_mode = true;
-                    frame.set_cursor((char_idx + 1) as u16, layout[1].y);
+                    render_command_line(frame, layout[1], &app.theme, ':', buffer, *char_idx);
+                }
+                Mode::Search {
+                    buffer,
+                    char_idx,
+                    backward,
+                    ..
+                } => {
+                    is_command_mode = true;
+                    let prompt = if *backward { '?' } else { '/' };
+                    render_command_line(frame, layout[1], &app.theme, prompt, buffer, *char_idx);
+                }
+                Mode::ConfirmSubstitute { substitution } => {
+                    is_command_mode = true;
+                    let prompt = format!(
+                        "replace with {} (y/n/a/q/l)?",
+                        substitution.current_replacement().unwrap_or_default()
+                    );
                     frame.render_widget(
-                        Paragraph::new(Line::from(format!(":{buffer}")))
-                            .bg(COMMAND_MODE_BACKGROUND),
+                        Paragraph::new(Line::from(prompt))
+                            .style(app.theme.ui(UiElement::CommandLine)),
                         layout[1],
                     );
                 }
@@ -280,8 +331,12 @@ pub fn run() -> Result<(), Box<dyn Error>> {
             }
 
             if !is_command_mode {
-                if let Some(first_log_line) = app.log.take_lines().next() {
-                    frame.render_widget(Paragraph::new(Line::from(first_log_line)), layout[1]);
+                if let Some((severity, first_log_line)) = app.log.take_entries().next() {
+                    frame.render_widget(
+                        Paragraph::new(Line::from(first_log_line))
+                            .style(app.theme.log_style(severity)),
+                        layout[1],
+                    );
                 }
             }
 
@@ -291,19 +346,34 @@ pub fn run() -> Result<(), Box<dyn Error>> {
                 .split(layout[2]);
 
             frame.render_widget(
-                Paragraph::new(Line::from(Span::from(app.current_mode.display_name()))),
+                Paragraph::new(Line::from(vec![
+                    Span::from(app.current_mode.display_name()),
+                    Span::from(" "),
+                    Span::styled(
+                        display_sequence(&app.pending_keys),
+                        app.theme.ui(UiElement::PendingKeys),
+                    ),
+                    Span::from(" "),
+                    Span::from(match_info),
+                ]))
+                .style(app.theme.ui(UiElement::StatusBar)),
                 status_layout[0],
             );
             frame.render_widget(
                 Paragraph::new(Line::from(Span::from(
                     Local::now().format("%H:%M:%S").to_string(),
                 )))
+                .style(app.theme.ui(UiElement::StatusBar))
                 .alignment(ratatui::layout::Alignment::Right),
                 status_layout[1],
             );
         })?;
 
-        if event::poll(Duration::from_millis(100))? {
+        if crate::keys::process_pending_keys_timeout(&mut app) {
+            break;
+        }
+
+        if event::poll(Duration::from_millis(app.options.pollinterval as u64))? {
             if let event::Event::Key(key) = event::read()? {
                 if crate::keys::process_keys(key, &mut app) {
                     break;
@@ -0,0 +1,245 @@
+use std::fmt::Display;
+
+#[derive(Clone, PartialEq, Eq)]
+pub enum OptionValue {
+    Bool(bool),
+    Number(usize),
+    String(String),
+}
+
+impl Display for OptionValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OptionValue::Bool(b) => write!(f, "{b}"),
+            OptionValue::Number(n) => write!(f, "{n}"),
+            OptionValue::String(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum OptionKind {
+    Bool,
+    Number,
+    String,
+}
+
+pub struct OptionInfo {
+    pub name: &'static str,
+    pub short_name: &'static str,
+    pub kind: OptionKind,
+    /// Whether each buffer has its own value, set with `:setlocal`. The global value is what
+    /// new buffers start with.
+    pub local: bool,
+}
+
+pub const OPTIONS: &[OptionInfo] = &[
+    OptionInfo {
+        name: "tabstop",
+        short_name: "ts",
+        kind: OptionKind::Number,
+        local: true,
+    },
+    OptionInfo {
+        name: "expandtab",
+        short_name: "et",
+        kind: OptionKind::Bool,
+        local: true,
+    },
+    OptionInfo {
+        name: "number",
+        short_name: "nu",
+        kind: OptionKind::Bool,
+        local: false,
+    },
+    OptionInfo {
+        name: "scrolloff",
+        short_name: "so",
+        kind: OptionKind::Number,
+        local: false,
+    },
+    OptionInfo {
+        name: "sidescrolloff",
+        short_name: "siso",
+        kind: OptionKind::Number,
+        local: false,
+    },
+    OptionInfo {
+        name: "theme",
+        short_name: "theme",
+        kind: OptionKind::String,
+        local: false,
+    },
+    OptionInfo {
+        name: "pollinterval",
+        short_name: "pi",
+        kind: OptionKind::Number,
+        local: false,
+    },
+    OptionInfo {
+        name: "timeoutlen",
+        short_name: "tm",
+        kind: OptionKind::Number,
+        local: false,
+    },
+];
+
+impl OptionInfo {
+    pub fn by_name(name: &str) -> Option<&'static OptionInfo> {
+        OPTIONS
+            .iter()
+            .find(|option| option.name == name || option.short_name == name)
+    }
+
+    pub fn parse_value(&self, value: &str) -> Result<OptionValue, String> {
+        match self.kind {
+            OptionKind::Bool => match value {
+                "true" | "on" | "1" => Ok(OptionValue::Bool(true)),
+                "false" | "off" | "0" => Ok(OptionValue::Bool(false)),
+                _ => Err(format!("{} expects true or false", self.name)),
+            },
+            OptionKind::Number => value
+                .parse()
+                .map(OptionValue::Number)
+                .map_err(|_| format!("{} expects a number", self.name)),
+            OptionKind::String => Ok(OptionValue::String(value.to_string())),
+        }
+    }
+}
+
+/// What `:set` was asked to do with an option.
+pub enum Assignment {
+    Set(&'static OptionInfo, OptionValue),
+    Toggle(&'static OptionInfo),
+    Show(&'static OptionInfo),
+}
+
+impl Assignment {
+    /// Parses one argument of `:set`: `opt=value`, `opt?`, `opt!`, `noopt` or `opt`, which
+    /// turns a bool option on and shows any other option.
+    pub fn parse(arg: &str) -> Result<Assignment, String> {
+        let lookup =
+            |name: &str| OptionInfo::by_name(name).ok_or_else(|| format!("Unknown option {name}"));
+        if let Some((name, value)) = arg.split_once('=') {
+            let option = lookup(name)?;
+            return Ok(Assignment::Set(option, option.parse_value(value)?));
+        }
+        if let Some(name) = arg.strip_suffix('?') {
+            return Ok(Assignment::Show(lookup(name)?));
+        }
+        if let Some(name) = arg.strip_suffix('!') {
+            let option = lookup(name)?;
+            if option.kind != OptionKind::Bool {
+                return Err(format!("{} is not a bool option", option.name));
+            }
+            return Ok(Assignment::Toggle(option));
+        }
+        if let Some(option) = arg
+            .strip_prefix("no")
+            .and_then(OptionInfo::by_name)
+            .filter(|option| option.kind == OptionKind::Bool)
+        {
+            return Ok(Assignment::Set(option, OptionValue::Bool(false)));
+        }
+        let option = lookup(arg)?;
+        Ok(match option.kind {
+            OptionKind::Bool => Assignment::Set(option, OptionValue::Bool(true)),
+            _ => Assignment::Show(option),
+        })
+    }
+}
+
+/// The options each buffer has its own value of.
+#[derive(Copy, Clone)]
+pub struct BufferOptions {
+    pub tabstop: usize,
+    /// Whether Tab and `>` insert spaces instead of a tab character
+    pub expandtab: bool,
+}
+
+impl Default for BufferOptions {
+    fn default() -> Self {
+        BufferOptions {
+            tabstop: 4,
+            expandtab: true,
+        }
+    }
+}
+
+impl BufferOptions {
+    pub fn get(&self, name: &str) -> Option<OptionValue> {
+        match name {
+            "tabstop" => Some(OptionValue::Number(self.tabstop)),
+            "expandtab" => Some(OptionValue::Bool(self.expandtab)),
+            _ => None,
+        }
+    }
+
+    pub fn set(&mut self, name: &str, value: OptionValue) -> Result<(), String> {
+        match (name, value) {
+            ("tabstop", OptionValue::Number(0)) => return Err("tabstop must be positive".into()),
+            ("tabstop", OptionValue::Number(n)) => self.tabstop = n,
+            ("expandtab", OptionValue::Bool(b)) => self.expandtab = b,
+            (name, value) => return Err(format!("Invalid value {value} for {name}")),
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone)]
+pub struct Options {
+    pub number: bool,
+    /// How many lines to keep visible above and below the cursor
+    pub scrolloff: usize,
+    /// How many columns to keep visible left and right of the cursor
+    pub sidescrolloff: usize,
+    pub theme: String,
+    /// How many milliseconds to wait for input before redrawing
+    pub pollinterval: usize,
+    /// How many milliseconds to wait for the next key of an ambiguous key sequence
+    pub timeoutlen: usize,
+    /// The values new buffers start with
+    pub buffer: BufferOptions,
+}
+
+impl Options {
+    pub fn new() -> Self {
+        Options {
+            number: true,
+            scrolloff: 0,
+            sidescrolloff: 0,
+            theme: "default".to_string(),
+            pollinterval: 100,
+            timeoutlen: 1000,
+            buffer: BufferOptions::default(),
+        }
+    }
+
+    pub fn get(&self, name: &str) -> Option<OptionValue> {
+        match name {
+            "number" => Some(OptionValue::Bool(self.number)),
+            "scrolloff" => Some(OptionValue::Number(self.scrolloff)),
+            "sidescrolloff" => Some(OptionValue::Number(self.sidescrolloff)),
+            "theme" => Some(OptionValue::String(self.theme.clone())),
+            "pollinterval" => Some(OptionValue::Number(self.pollinterval)),
+            "timeoutlen" => Some(OptionValue::Number(self.timeoutlen)),
+            name => self.buffer.get(name),
+        }
+    }
+
+    pub fn set(&mut self, name: &str, value: OptionValue) -> Result<(), String> {
+        match (name, value) {
+            ("number", OptionValue::Bool(b)) => self.number = b,
+            ("scrolloff", OptionValue::Number(n)) => self.scrolloff = n,
+            ("sidescrolloff", OptionValue::Number(n)) => self.sidescrolloff = n,
+            ("theme", OptionValue::String(theme)) => self.theme = theme,
+            ("pollinterval", OptionValue::Number(0)) => {
+                return Err("pollinterval must be positive".into())
+            }
+            ("pollinterval", OptionValue::Number(n)) => self.pollinterval = n,
+            ("timeoutlen", OptionValue::Number(n)) => self.timeoutlen = n,
+            (name, value) => return self.buffer.set(name, value),
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,73 @@
+use super::{
+    fuzzy::{fuzzy_match, FuzzyMatch},
+    window::Window,
+};
+
+/// The state of the window picker, which lists the open windows with the most recently used
+/// first and narrows them down by a fuzzy query.
+pub struct WindowPicker {
+    pub query: String,
+    pub char_idx: usize,
+    /// Whether typed keys edit the query, after `/`
+    pub filtering: bool,
+    /// The indices of the windows matching the query along with the match in their label
+    pub entries: Vec<(usize, FuzzyMatch)>,
+    pub selected: usize,
+}
+
+/// The text the query is matched against, the title and the path if it differs.
+pub fn label(window: &Window) -> String {
+    let title = window.resolve_title();
+    match &window.buffer().attached_file_path {
+        Some(path) if *path != title => format!("{title} {path}"),
+        _ => title,
+    }
+}
+
+impl WindowPicker {
+    pub fn new(windows: &[Window]) -> Self {
+        let mut picker = WindowPicker {
+            query: String::new(),
+            char_idx: 0,
+            filtering: false,
+            entries: Vec::new(),
+            selected: 0,
+        };
+        picker.update_entries(windows);
+        picker
+    }
+
+    /// Matches the windows against the query again, after it or the windows changed.
+    pub fn update_entries(&mut self, windows: &[Window]) {
+        let mut entries: Vec<(usize, FuzzyMatch)> = windows
+            .iter()
+            .enumerate()
+            .filter_map(|(index, window)| {
+                fuzzy_match(&self.query, &label(window)).map(|m| (index, m))
+            })
+            .collect();
+        entries.sort_by(|(a, match_a), (b, match_b)| {
+            match_b
+                .score
+                .cmp(&match_a.score)
+                .then(windows[*b].last_used.cmp(&windows[*a].last_used))
+        });
+        self.entries = entries;
+        self.selected = self.selected.min(self.entries.len().saturating_sub(1));
+    }
+
+    /// Returns the index of the selected window.
+    pub fn selected_window(&self) -> Option<usize> {
+        self.entries.get(self.selected).map(|(index, _)| *index)
+    }
+
+    pub fn select_next(&mut self) {
+        if self.selected + 1 < self.entries.len() {
+            self.selected += 1;
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+}
@@ -0,0 +1,152 @@
+use std::{
+    collections::{BTreeMap, VecDeque},
+    io::{stderr, Write},
+};
+
+use base64::Engine;
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum RegisterKind {
+    Charwise,
+    Linewise,
+    /// One line of text per line of a block selection
+    Blockwise,
+}
+
+#[derive(Clone)]
+pub struct RegisterContent {
+    pub text: String,
+    pub kind: RegisterKind,
+}
+
+impl RegisterContent {
+    pub fn new(text: impl Into<String>, kind: RegisterKind) -> Self {
+        RegisterContent {
+            text: text.into(),
+            kind,
+        }
+    }
+}
+
+const DELETE_HISTORY_LENGTH: usize = 9;
+
+/// Holds everything that was yanked or deleted.
+///
+/// - `"` is the unnamed register, it always holds the last yank or delete
+/// - `0` holds the last yank, `1` to `9` the last deletes with the newest one in `1`
+/// - `a` to `z` are set explicitly, using `A` to `Z` appends to them instead
+/// - `_` is the black hole register, writing to it does nothing
+/// - `+` is also sent to the terminal's clipboard with an OSC 52 escape sequence
+pub struct Registers {
+    unnamed: Option<RegisterContent>,
+    last_yank: Option<RegisterContent>,
+    deletes: VecDeque<RegisterContent>,
+    named: BTreeMap<char, RegisterContent>,
+    clipboard: Option<RegisterContent>,
+}
+
+impl Registers {
+    pub fn new() -> Self {
+        Registers {
+            unnamed: None,
+            last_yank: None,
+            deletes: VecDeque::new(),
+            named: BTreeMap::new(),
+            clipboard: None,
+        }
+    }
+
+    pub fn is_valid_name(register: char) -> bool {
+        register.is_ascii_alphanumeric() || matches!(register, '"' | '_' | '+')
+    }
+
+    pub fn yank(&mut self, register: Option<char>, content: RegisterContent) {
+        if register.is_none() || register == Some('"') {
+            self.last_yank = Some(content.clone());
+        }
+        self.store(register, content);
+    }
+
+    pub fn delete(&mut self, register: Option<char>, content: RegisterContent) {
+        if register.is_none() || register == Some('"') {
+            self.deletes.push_front(content.clone());
+            self.deletes.truncate(DELETE_HISTORY_LENGTH);
+        }
+        self.store(register, content);
+    }
+
+    fn store(&mut self, register: Option<char>, content: RegisterContent) {
+        let content = match register {
+            Some('_') => return,
+            Some('+') => {
+                copy_to_terminal_clipboard(&content.text);
+                self.clipboard = Some(content.clone());
+                content
+            }
+            Some(c) if c.is_ascii_lowercase() => {
+                self.named.insert(c, content.clone());
+                content
+            }
+            Some(c) if c.is_ascii_uppercase() => {
+                let entry = self
+                    .named
+                    .entry(c.to_ascii_lowercase())
+                    .or_insert_with(|| RegisterContent::new("", content.kind));
+                if content.kind == RegisterKind::Linewise
+                    && entry.kind != RegisterKind::Linewise
+                    && !entry.text.is_empty()
+                {
+                    entry.text.push('\n');
+                    entry.kind = RegisterKind::Linewise;
+                } else if entry.kind == RegisterKind::Blockwise && !entry.text.is_empty() {
+                    entry.text.push('\n');
+                }
+                entry.text.push_str(&content.text);
+                entry.clone()
+            }
+            _ => content,
+        };
+        self.unnamed = Some(content);
+    }
+
+    pub fn get(&self, register: Option<char>) -> Option<&RegisterContent> {
+        match register {
+            None | Some('"') => self.unnamed.as_ref(),
+            Some('0') => self.last_yank.as_ref(),
+            Some(c @ '1'..='9') => self.deletes.get(c as usize - '1' as usize),
+            Some('+') => self.clipboard.as_ref(),
+            Some(c) if c.is_ascii_alphabetic() => self.named.get(&c.to_ascii_lowercase()),
+            _ => None,
+        }
+    }
+
+    /// Lists all registers that hold something, in the order `:registers` shows them.
+    pub fn entries(&self) -> Vec<(char, &RegisterContent)> {
+        let mut entries = Vec::new();
+        if let Some(content) = &self.unnamed {
+            entries.push(('"', content));
+        }
+        if let Some(content) = &self.last_yank {
+            entries.push(('0', content));
+        }
+        for (i, content) in self.deletes.iter().enumerate() {
+            entries.push((char::from_digit(i as u32 + 1, 10).unwrap(), content));
+        }
+        for (name, content) in self.named.iter() {
+            entries.push((*name, content));
+        }
+        if let Some(content) = &self.clipboard {
+            entries.push(('+', content));
+        }
+        entries
+    }
+}
+
+/// Asks the terminal to put `text` into the system clipboard. This works over SSH as well,
+/// as long as the terminal emulator supports OSC 52.
+fn copy_to_terminal_clipboard(text: &str) {
+    let encoded = base64::engine::general_purpose::STANDARD.encode(text);
+    let mut stderr = stderr();
+    let _ = write!(stderr, "\x1b]52;c;{encoded}\x07");
+    let _ = stderr.flush();
+}
@@ -0,0 +1,102 @@
+use std::ops::Range;
+
+use regex::{Regex, RegexBuilder};
+use ropey::Rope;
+
+/// The last search, which `n` and `N` repeat.
+pub struct Search {
+    pub pattern: String,
+    pub backward: bool,
+}
+
+/// Builds the regex for a search pattern. Like smart-case in vim, the search ignores case
+/// unless the pattern contains an uppercase letter.
+pub fn build_regex(pattern: &str) -> Result<Regex, regex::Error> {
+    build_regex_ignoring_case(pattern, !pattern.chars().any(char::is_uppercase))
+}
+
+pub fn build_regex_ignoring_case(pattern: &str, ignore_case: bool) -> Result<Regex, regex::Error> {
+    RegexBuilder::new(pattern)
+        .case_insensitive(ignore_case)
+        .multi_line(true)
+        .build()
+}
+
+/// All matches of a pattern in the text of a window, cached until the text or pattern changes.
+pub struct SearchMatches {
+    pattern: String,
+    text_version: usize,
+    /// The char ranges of the matches in order, empty if the pattern is invalid
+    pub ranges: Vec<Range<usize>>,
+}
+
+impl SearchMatches {
+    pub fn find(text: &Rope, text_version: usize, pattern: &str) -> Self {
+        let ranges = match build_regex(pattern) {
+            Ok(regex) => regex
+                .find_iter(&text.to_string())
+                .filter(|m| !m.is_empty())
+                .map(|m| text.byte_to_char(m.start())..text.byte_to_char(m.end()))
+                .collect(),
+            Err(_) => Vec::new(),
+        };
+        SearchMatches {
+            pattern: pattern.to_string(),
+            text_version,
+            ranges,
+        }
+    }
+
+    pub fn is_current(&self, text_version: usize, pattern: &str) -> bool {
+        self.text_version == text_version && self.pattern == pattern
+    }
+
+    /// Returns the index of the match that contains `char_index`.
+    pub fn index_at(&self, char_index: usize) -> Option<usize> {
+        self.ranges
+            .binary_search_by(|range| {
+                if range.end <= char_index {
+                    std::cmp::Ordering::Less
+                } else if range.start > char_index {
+                    std::cmp::Ordering::Greater
+                } else {
+                    std::cmp::Ordering::Equal
+                }
+            })
+            .ok()
+    }
+
+    /// Returns the start of the `count`th match after (or before) `char_index`, wrapping around
+    /// the end of the text, along with whether it wrapped.
+    pub fn next_match(
+        &self,
+        char_index: usize,
+        backward: bool,
+        count: usize,
+    ) -> Option<(usize, bool)> {
+        if self.ranges.is_empty() {
+            return None;
+        }
+        let mut position = char_index;
+        let mut wrapped = false;
+        for _ in 0..count {
+            let found = if backward {
+                self.ranges.iter().rev().find(|r| r.start < position)
+            } else {
+                self.ranges.iter().find(|r| r.start > position)
+            };
+            position = match found {
+                Some(range) => range.start,
+                None => {
+                    wrapped = true;
+                    if backward {
+                        self.ranges[self.ranges.len() - 1].start
+                    } else {
+                        self.ranges[0].start
+                    }
+                }
+            };
+        }
+        Some((position, wrapped))
+    }
+}
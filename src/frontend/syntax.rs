@@ -0,0 +1,876 @@
+use std::{
+    cmp::Reverse,
+    ops::Range,
+    time::{Duration, Instant},
+};
+
+use ropey::Rope;
+use tree_sitter::{InputEdit, Node, Parser, Point, Query, QueryCursor, QueryMatch, Tree};
+
+use super::{history::Edit, language::Language};
+
+/// How long parsing may take per frame. A longer parse, like the first one of a big file,
+/// continues in the next frame and the text is drawn without highlights until it's done.
+const PARSE_BUDGET: Duration = Duration::from_millis(10);
+/// How long highlighting the text outside of the visible lines may take per frame
+const HIGHLIGHT_BUDGET: Duration = Duration::from_millis(5);
+/// How many bytes outside of the visible lines are highlighted at once
+const HIGHLIGHT_CHUNK: usize = 16 * 1024;
+/// How deep injected languages may nest, like Rust in a macro in a Markdown code fence
+const MAX_INJECTION_DEPTH: usize = 4;
+
+/// The syntax tree of a buffer, which is parsed again incrementally after edits, and the
+/// highlights of its text, which are recomputed only where the text or the tree changed.
+pub struct Syntax {
+    pub language: Language,
+    parser: Parser,
+    tree: Option<Tree>,
+    /// Whether the tree was edited since it was last parsed
+    outdated: bool,
+    /// Whether the parser ran out of time and continues where it stopped when called again
+    paused: bool,
+    /// Byte ranges of the text and their capture names, sorted and not overlapping
+    highlights: Vec<(Range<usize>, &'static str)>,
+    /// Byte ranges whose highlights have to be recomputed, sorted and not overlapping
+    pending: Vec<Range<usize>>,
+    injections: Injections,
+}
+
+/// The code of other languages embedded in the text, kept between highlights so it's only
+/// parsed again where it was edited.
+#[derive(Default)]
+struct Injections {
+    parsers: Vec<(Language, Parser)>,
+    layers: Vec<Layer>,
+}
+
+/// The tree of one piece of injected code, like a code fence in Markdown.
+struct Layer {
+    language: Language,
+    depth: usize,
+    ranges: Vec<tree_sitter::Range>,
+    tree: Tree,
+    /// Whether an edit touched the code since it was parsed
+    edited: bool,
+    /// Whether the injection query still found the code during the current requery
+    found: bool,
+}
+
+impl Layer {
+    fn span(&self) -> Range<usize> {
+        span(&self.ranges)
+    }
+}
+
+impl Syntax {
+    pub fn new(language: Language) -> Option<Syntax> {
+        let mut parser = Parser::new();
+        parser.set_language(language.tree_sitter_language()).ok()?;
+        Some(Syntax {
+            language,
+            parser,
+            tree: None,
+            outdated: true,
+            paused: false,
+            highlights: Vec::new(),
+            pending: Vec::new(),
+            injections: Injections::default(),
+        })
+    }
+
+    /// Moves the tree, the highlights and the pending ranges along with an edit of the text.
+    pub fn edit(&mut self, edit: &InputEdit) {
+        if self.paused {
+            // The paused parse is of the old text
+            self.parser.reset();
+            self.paused = false;
+        }
+        if let Some(tree) = &mut self.tree {
+            tree.edit(edit);
+        }
+        self.outdated = true;
+        let shift = |byte: usize| shift_byte(edit, byte);
+        self.highlights.retain_mut(|(range, _)| {
+            *range = shift(range.start)..shift(range.end);
+            range.start < range.end
+        });
+        for range in &mut self.pending {
+            *range = shift(range.start)..shift(range.end);
+        }
+        for layer in &mut self.injections.layers {
+            let span = layer.span();
+            layer.edited |= edit.start_byte <= span.end && edit.old_end_byte >= span.start;
+            layer.tree.edit(edit);
+            for range in &mut layer.ranges {
+                range.start_byte = shift(range.start_byte);
+                range.end_byte = shift(range.end_byte);
+            }
+        }
+        add_range(&mut self.pending, edit.start_byte..edit.new_end_byte);
+    }
+
+    /// Parses the text if it changed, reusing the parts of the old tree that weren't edited.
+    pub fn update(&mut self, text: &Rope) {
+        self.parse_within(text, None);
+    }
+
+    /// Parses the text if it changed, giving up after `budget` to continue in the next call.
+    fn parse_within(&mut self, text: &Rope, budget: Option<Duration>) {
+        if !self.outdated {
+            return;
+        }
+        self.parser
+            .set_timeout_micros(budget.map_or(0, |budget| budget.as_micros() as u64));
+        let tree = parse(&mut self.parser, text, self.tree.as_ref());
+        self.paused = tree.is_none();
+        let Some(tree) = tree else {
+            return;
+        };
+        match &self.tree {
+            Some(old_tree) => {
+                for range in old_tree.changed_ranges(&tree) {
+                    add_range(&mut self.pending, range.start_byte..range.end_byte);
+                }
+            }
+            None => add_range(&mut self.pending, 0..text.len_bytes()),
+        }
+        self.tree = Some(tree);
+        self.outdated = false;
+    }
+
+    /// Returns the tree of the text as it was when `update` was last called.
+    pub fn tree(&self) -> Option<&Tree> {
+        self.tree.as_ref().filter(|_| !self.outdated)
+    }
+
+    /// Recomputes the outdated highlights in `visible`, a byte range of the text, and then
+    /// as many of the others as the time budget allows. Nothing changes while the text is
+    /// still being parsed.
+    pub fn highlight(&mut self, text: &Rope, visible: Range<usize>) {
+        self.parse_within(text, Some(PARSE_BUDGET));
+        let Some(tree) = self.tree().cloned() else {
+            return;
+        };
+        let visible_pending: Vec<Range<usize>> = self
+            .pending
+            .iter()
+            .filter(|range| range.start <= visible.end && range.end >= visible.start)
+            .map(|range| range.start.max(visible.start)..range.end.min(visible.end))
+            .collect();
+        for range in visible_pending {
+            self.requery(text, &tree, range);
+        }
+        let start = Instant::now();
+        while let Some(range) = self.pending.first().cloned() {
+            if start.elapsed() >= HIGHLIGHT_BUDGET {
+                break;
+            }
+            self.requery(
+                text,
+                &tree,
+                range.start..range.end.min(range.start + HIGHLIGHT_CHUNK),
+            );
+        }
+    }
+
+    /// Returns the capture name of the byte at `byte_index`.
+    pub fn find_highlight(&self, byte_index: usize) -> Option<&'static str> {
+        self.highlights
+            .binary_search_by(|(range, _)| {
+                if range.contains(&byte_index) {
+                    std::cmp::Ordering::Equal
+                } else {
+                    range.start.cmp(&byte_index)
+                }
+            })
+            .ok()
+            .map(|i| self.highlights[i].1)
+    }
+
+    /// Replaces the highlights of the lines `range` touches with freshly queried ones.
+    fn requery(&mut self, text: &Rope, tree: &Tree, range: Range<usize>) {
+        let range = expand_to_lines(text, range);
+        remove_range(&mut self.pending, &range);
+        let overlaps = |layer: &Layer| {
+            let span = layer.span();
+            span.start < range.end && span.end > range.start
+        };
+        for layer in self.injections.layers.iter_mut() {
+            layer.found = !overlaps(layer);
+        }
+        let mut names = vec![None; range.len()];
+        paint(
+            self.language,
+            tree,
+            text,
+            &range,
+            &mut names,
+            &mut self.injections,
+            0,
+        );
+        // Code that is no longer injected, like a code fence that was deleted
+        self.injections.layers.retain(|layer| layer.found);
+
+        let first = self
+            .highlights
+            .partition_point(|(r, _)| r.end <= range.start);
+        let last = self
+            .highlights
+            .partition_point(|(r, _)| r.start < range.end);
+        let mut replacement = Vec::new();
+        if let Some((r, name)) = self.highlights[first..last].first() {
+            if r.start < range.start {
+                replacement.push((r.start..range.start, *name));
+            }
+        }
+        let mut run_start = 0;
+        for i in 1..=names.len() {
+            if i == names.len() || names[i] != names[run_start] {
+                if let Some(name) = names[run_start] {
+                    replacement.push((range.start + run_start..range.start + i, name));
+                }
+                run_start = i;
+            }
+        }
+        if let Some((r, name)) = self.highlights[first..last].last() {
+            if r.end > range.end {
+                replacement.push((range.end..r.end, *name));
+            }
+        }
+        self.highlights.splice(first..last, replacement);
+    }
+}
+
+/// Describes an edit for tree-sitter. `text` is the text right after the edit.
+pub fn input_edit(edit: &Edit, text: &Rope) -> InputEdit {
+    let start_byte = text.char_to_byte(edit.char_idx());
+    let start_position = point_at(text, start_byte);
+    match edit {
+        Edit::Insert { text: inserted, .. } => InputEdit {
+            start_byte,
+            old_end_byte: start_byte,
+            new_end_byte: start_byte + inserted.len(),
+            start_position,
+            old_end_position: start_position,
+            new_end_position: point_at(text, start_byte + inserted.len()),
+        },
+        Edit::Remove { text: removed, .. } => InputEdit {
+            start_byte,
+            old_end_byte: start_byte + removed.len(),
+            new_end_byte: start_byte,
+            start_position,
+            old_end_position: advance(start_position, removed),
+            new_end_position: start_position,
+        },
+    }
+}
+
+fn point_at(text: &Rope, byte_index: usize) -> Point {
+    let row = text.byte_to_line(byte_index);
+    Point::new(row, byte_index - text.line_to_byte(row))
+}
+
+/// Returns the point at the end of `s` if it starts at `point`.
+fn advance(point: Point, s: &str) -> Point {
+    match s.rfind('\n') {
+        Some(last_newline) => Point::new(
+            point.row + s.matches('\n').count(),
+            s.len() - last_newline - 1,
+        ),
+        None => Point::new(point.row, point.column + s.len()),
+    }
+}
+
+fn shift_byte(edit: &InputEdit, byte: usize) -> usize {
+    if byte <= edit.start_byte {
+        byte
+    } else if byte >= edit.old_end_byte {
+        byte - edit.old_end_byte + edit.new_end_byte
+    } else {
+        edit.start_byte
+    }
+}
+
+/// Adds `range` to sorted, non overlapping `ranges`, merging it with the ones it touches.
+fn add_range(ranges: &mut Vec<Range<usize>>, range: Range<usize>) {
+    let first = ranges.partition_point(|r| r.end < range.start);
+    let last = ranges.partition_point(|r| r.start <= range.end);
+    let merged = ranges[first..last]
+        .iter()
+        .fold(range, |acc, r| acc.start.min(r.start)..acc.end.max(r.end));
+    ranges.splice(first..last, [merged]);
+}
+
+/// Removes the bytes of `cut` from sorted, non overlapping `ranges`.
+fn remove_range(ranges: &mut Vec<Range<usize>>, cut: &Range<usize>) {
+    let mut remaining = Vec::with_capacity(ranges.len() + 1);
+    for range in ranges.drain(..) {
+        if range.end < cut.start || range.start > cut.end {
+            remaining.push(range);
+            continue;
+        }
+        if range.start < cut.start {
+            remaining.push(range.start..cut.start);
+        }
+        if range.end > cut.end {
+            remaining.push(cut.end..range.end);
+        }
+    }
+    *ranges = remaining;
+}
+
+fn expand_to_lines(text: &Rope, range: Range<usize>) -> Range<usize> {
+    let start = text.line_to_byte(text.byte_to_line(range.start));
+    let end_line = text.byte_to_line(range.end);
+    let end = if end_line + 1 < text.len_lines() {
+        text.line_to_byte(end_line + 1)
+    } else {
+        text.len_bytes()
+    };
+    start..end
+}
+
+impl Injections {
+    /// Returns the tree of the `language` code at `ranges`, parsing it only if it's new or
+    /// was edited since it was last parsed.
+    fn tree(
+        &mut self,
+        language: Language,
+        depth: usize,
+        ranges: Vec<tree_sitter::Range>,
+        text: &Rope,
+    ) -> Option<Tree> {
+        let span = span(&ranges);
+        let index = self.layers.iter().position(|layer| {
+            let layer_span = layer.span();
+            layer.language == language
+                && layer.depth == depth
+                && layer_span.start < span.end
+                && layer_span.end > span.start
+        });
+        if let Some(layer) = index.map(|i| &mut self.layers[i]) {
+            layer.found = true;
+            let same_ranges = layer.ranges.len() == ranges.len()
+                && layer
+                    .ranges
+                    .iter()
+                    .zip(&ranges)
+                    .all(|(a, b)| a.start_byte == b.start_byte && a.end_byte == b.end_byte);
+            if !layer.edited && same_ranges {
+                return Some(layer.tree.clone());
+            }
+        }
+
+        let parser = match self.parsers.iter().position(|(l, _)| *l == language) {
+            Some(i) => &mut self.parsers[i].1,
+            None => {
+                let mut parser = Parser::new();
+                parser.set_language(language.tree_sitter_language()).ok()?;
+                self.parsers.push((language, parser));
+                &mut self.parsers.last_mut()?.1
+            }
+        };
+        parser.set_included_ranges(&ranges).ok()?;
+        let old_tree = index.map(|i| &self.layers[i].tree);
+        let tree = parse(parser, text, old_tree)?;
+        let layer = Layer {
+            language,
+            depth,
+            ranges,
+            tree: tree.clone(),
+            edited: false,
+            found: true,
+        };
+        match index {
+            Some(i) => self.layers[i] = layer,
+            None => self.layers.push(layer),
+        }
+        Some(tree)
+    }
+}
+
+/// Returns the bytes from the start of the first of `ranges` to the end of the last.
+fn span(ranges: &[tree_sitter::Range]) -> Range<usize> {
+    match (ranges.first(), ranges.last()) {
+        (Some(first), Some(last)) => first.start_byte..last.end_byte,
+        _ => 0..0,
+    }
+}
+
+/// Parses the text straight from the chunks of the rope.
+pub fn parse(parser: &mut Parser, text: &Rope, old_tree: Option<&Tree>) -> Option<Tree> {
+    parser.parse_with(
+        &mut |byte_index, _| {
+            if byte_index >= text.len_bytes() {
+                return &[] as &[u8];
+            }
+            let (chunk, chunk_byte_index, ..) = text.chunk_at_byte(byte_index);
+            &chunk.as_bytes()[byte_index - chunk_byte_index..]
+        },
+        old_tree,
+    )
+}
+
+/// Writes the capture names of the bytes in `range` into `names`. Inner nodes override the
+/// nodes around them, the first pattern matching a node wins and injected languages are
+/// painted over the code they are embedded in.
+fn paint(
+    language: Language,
+    tree: &Tree,
+    text: &Rope,
+    range: &Range<usize>,
+    names: &mut [Option<&'static str>],
+    injections: &mut Injections,
+    depth: usize,
+) {
+    let Ok(queries) = language.queries() else {
+        return;
+    };
+    let node_text = |node: Node| {
+        text.byte_slice(node.byte_range())
+            .chunks()
+            .map(str::as_bytes)
+    };
+    let locals = match &queries.locals {
+        Some(locals_query) => Locals::find(locals_query, tree, text, range),
+        None => Locals::default(),
+    };
+    let mut cursor = QueryCursor::new();
+    cursor.set_byte_range(range.clone());
+    let mut captures = Vec::new();
+    for (query_match, index) in cursor.captures(&queries.highlights, tree.root_node(), node_text) {
+        let capture = query_match.captures[index];
+        let is_local = locals.contains(&capture.node.byte_range());
+        if !applies_to_locals(&queries.highlights, query_match.pattern_index, is_local) {
+            continue;
+        }
+        if let Some(name) = queries.highlight_names[capture.index as usize] {
+            captures.push((capture.node.byte_range(), query_match.pattern_index, name));
+        }
+    }
+    captures
+        .sort_by_key(|(r, pattern_index, _)| (r.start, Reverse(r.end), Reverse(*pattern_index)));
+    let mut paint_range = |r: Range<usize>, name| {
+        for byte in r.start.max(range.start)..r.end.min(range.end) {
+            names[byte - range.start] = Some(name);
+        }
+    };
+    for (r, _, name) in captures {
+        paint_range(r, name);
+    }
+    // References to local variables look like their definitions, like a parameter in the body
+    let mut definition_names = Vec::new();
+    for (reference, definition) in &locals.references {
+        if reference.end <= range.start || reference.start >= range.end {
+            continue;
+        }
+        let name = match definition_names.iter().find(|(d, _)| d == definition) {
+            Some((_, name)) => *name,
+            None => {
+                let name = definition_name(language, tree, text, definition);
+                definition_names.push((definition.clone(), name));
+                name
+            }
+        };
+        if let Some(name) = name {
+            paint_range(reference.clone(), name);
+        }
+    }
+
+    let Some(injection_query) = &queries.injections else {
+        return;
+    };
+    if depth == MAX_INJECTION_DEPTH {
+        return;
+    }
+    let mut cursor = QueryCursor::new();
+    cursor.set_byte_range(range.clone());
+    for query_match in cursor.matches(injection_query, tree.root_node(), node_text) {
+        let Some((language, ranges)) = injection(injection_query, &query_match, text) else {
+            continue;
+        };
+        if let Some(injected_tree) = injections.tree(language, depth + 1, ranges, text) {
+            paint(
+                language,
+                &injected_tree,
+                text,
+                range,
+                names,
+                injections,
+                depth + 1,
+            );
+        }
+    }
+}
+
+/// The local variables in and around the painted code, found by the locals query.
+#[derive(Default)]
+struct Locals {
+    /// Byte ranges of the identifiers that define a local variable, sorted
+    definitions: Vec<Range<usize>>,
+    /// Byte ranges of the identifiers that refer to a local variable and of its definition,
+    /// sorted by the reference
+    references: Vec<(Range<usize>, Range<usize>)>,
+}
+
+/// A scope of local variables, like a function or a block.
+struct Scope {
+    end: usize,
+    /// Whether the variables of the scopes around it are visible inside
+    inherits: bool,
+    definitions: Vec<(String, Range<usize>)>,
+}
+
+impl Locals {
+    /// Resolves the references in `range` to the definitions in the scopes around them. The
+    /// top level nodes touching `range` are searched as a whole, so definitions above the
+    /// range are found.
+    fn find(query: &Query, tree: &Tree, text: &Rope, range: &Range<usize>) -> Locals {
+        let root = tree.root_node();
+        let mut cursor = root.walk();
+        let search_range = root
+            .children(&mut cursor)
+            .filter(|child| child.start_byte() <= range.end && child.end_byte() >= range.start)
+            .fold(range.clone(), |acc, child| {
+                acc.start.min(child.start_byte())..acc.end.max(child.end_byte())
+            });
+        let node_text = |node: Node| {
+            text.byte_slice(node.byte_range())
+                .chunks()
+                .map(str::as_bytes)
+        };
+        let mut cursor = QueryCursor::new();
+        cursor.set_byte_range(search_range);
+        let mut scopes = vec![Scope {
+            end: usize::MAX,
+            inherits: false,
+            definitions: Vec::new(),
+        }];
+        let mut locals = Locals::default();
+        for (query_match, index) in cursor.captures(query, root, node_text) {
+            let capture = query_match.captures[index];
+            let node_range = capture.node.byte_range();
+            while scopes.len() > 1 && scopes.last().is_some_and(|s| s.end <= node_range.start) {
+                scopes.pop();
+            }
+            match query.capture_names()[capture.index as usize].as_str() {
+                "local.scope" => {
+                    let inherits = !query
+                        .property_settings(query_match.pattern_index)
+                        .iter()
+                        .any(|property| {
+                            &*property.key == "local.scope-inherits"
+                                && property.value.as_deref() == Some("false")
+                        });
+                    scopes.push(Scope {
+                        end: node_range.end,
+                        inherits,
+                        definitions: Vec::new(),
+                    });
+                }
+                "local.definition" => {
+                    let name = text.byte_slice(node_range.clone()).to_string();
+                    if let Some(scope) = scopes.last_mut() {
+                        scope.definitions.push((name, node_range.clone()));
+                    }
+                    locals.definitions.push(node_range);
+                }
+                // The same identifier is often captured as a definition and a reference
+                "local.reference" if locals.definitions.last() != Some(&node_range) => {
+                    let name = text.byte_slice(node_range.clone()).to_string();
+                    for scope in scopes.iter().rev() {
+                        let definition = scope.definitions.iter().rev().find(|(n, _)| *n == name);
+                        if let Some((_, definition)) = definition {
+                            locals.references.push((node_range, definition.clone()));
+                            break;
+                        }
+                        if !scope.inherits {
+                            break;
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        locals
+    }
+
+    /// Returns whether the identifier at `range` defines or refers to a local variable.
+    fn contains(&self, range: &Range<usize>) -> bool {
+        self.definitions
+            .binary_search_by_key(&range.start, |r| r.start)
+            .is_ok_and(|i| self.definitions[i] == *range)
+            || self
+                .references
+                .binary_search_by_key(&range.start, |(r, _)| r.start)
+                .is_ok_and(|i| self.references[i].0 == *range)
+    }
+}
+
+/// Returns whether a highlight pattern applies, given the `(#is? local)` or `(#is-not? local)`
+/// it might have.
+fn applies_to_locals(query: &Query, pattern_index: usize, is_local: bool) -> bool {
+    query
+        .property_predicates(pattern_index)
+        .iter()
+        .all(|(property, is_positive)| &*property.key != "local" || *is_positive == is_local)
+}
+
+/// Returns the capture name the highlights query gives the definition of a local variable.
+fn definition_name(
+    language: Language,
+    tree: &Tree,
+    text: &Rope,
+    definition: &Range<usize>,
+) -> Option<&'static str> {
+    let queries = language.queries().ok()?;
+    let node_text = |node: Node| {
+        text.byte_slice(node.byte_range())
+            .chunks()
+            .map(str::as_bytes)
+    };
+    let mut cursor = QueryCursor::new();
+    cursor.set_byte_range(definition.clone());
+    cursor
+        .captures(&queries.highlights, tree.root_node(), node_text)
+        .map(|(query_match, index)| (query_match.pattern_index, query_match.captures[index]))
+        .filter(|(pattern_index, capture)| {
+            capture.node.byte_range() == *definition
+                && applies_to_locals(&queries.highlights, *pattern_index, true)
+        })
+        .filter_map(|(pattern_index, capture)| {
+            Some((
+                pattern_index,
+                queries.highlight_names[capture.index as usize]?,
+            ))
+        })
+        .min_by_key(|(pattern_index, _)| *pattern_index)
+        .map(|(_, name)| name)
+}
+
+/// Returns the language an injection match names and the ranges of the code in it.
+fn injection(
+    query: &Query,
+    query_match: &QueryMatch,
+    text: &Rope,
+) -> Option<(Language, Vec<tree_sitter::Range>)> {
+    let mut name = None;
+    let mut content = None;
+    for capture in query_match.captures {
+        match query.capture_names()[capture.index as usize].as_str() {
+            "injection.language" => {
+                name = Some(text.byte_slice(capture.node.byte_range()).to_string())
+            }
+            "injection.content" => content = Some(capture.node),
+            _ => {}
+        }
+    }
+    let mut include_children = false;
+    for property in query.property_settings(query_match.pattern_index) {
+        match &*property.key {
+            "injection.language" if name.is_none() => {
+                name = property.value.as_deref().map(str::to_string)
+            }
+            "injection.include-children" => include_children = true,
+            _ => {}
+        }
+    }
+    let language = Language::by_injection_name(&name?)?;
+    let ranges = content_ranges(content?, include_children);
+    (!ranges.is_empty()).then_some((language, ranges))
+}
+
+/// Returns the ranges of `node`, leaving out its children unless `include_children` is set.
+fn content_ranges(node: Node, include_children: bool) -> Vec<tree_sitter::Range> {
+    if include_children {
+        return vec![node.range()];
+    }
+    let mut ranges = Vec::new();
+    let mut start = (node.start_byte(), node.start_position());
+    let mut cursor = node.walk();
+    let ends = node
+        .children(&mut cursor)
+        .map(|child| (child.start_byte(), child.start_position(), Some(child)))
+        .chain([(node.end_byte(), node.end_position(), None)]);
+    for (end_byte, end_point, child) in ends {
+        if end_byte > start.0 {
+            ranges.push(tree_sitter::Range {
+                start_byte: start.0,
+                end_byte,
+                start_point: start.1,
+                end_point,
+            });
+        }
+        if let Some(child) = child {
+            start = (child.end_byte(), child.end_position());
+        }
+    }
+    ranges
+}
+
+#[cfg(test)]
+mod tests {
+    use ropey::Rope;
+
+    use tree_sitter::Query;
+
+    use crate::frontend::{history::Edit, language::Language};
+
+    use super::{applies_to_locals, input_edit, Syntax};
+
+    fn highlighted(language: &str, text: &str) -> (Syntax, Rope) {
+        let text = Rope::from_str(text);
+        let mut syntax = Syntax::new(Language::by_injection_name(language).unwrap()).unwrap();
+        syntax.highlight(&text, 0..text.len_bytes());
+        (syntax, text)
+    }
+
+    /// Returns the capture name of the `n`th occurrence of `word`.
+    fn highlight_of(syntax: &Syntax, text: &Rope, word: &str, n: usize) -> Option<&'static str> {
+        let byte = text.to_string().match_indices(word).nth(n).unwrap().0;
+        syntax.find_highlight(byte)
+    }
+
+    #[test]
+    fn references_to_parameters_look_like_parameters() {
+        let (syntax, text) = highlighted(
+            "rust",
+            "fn f(alpha: i32) -> i32 {\n    let beta = alpha;\n    beta + alpha\n}\n\nfn g() {\n    alpha;\n}\n",
+        );
+        assert_eq!(
+            highlight_of(&syntax, &text, "alpha", 0),
+            Some("variable.parameter")
+        );
+        assert_eq!(
+            highlight_of(&syntax, &text, "alpha", 1),
+            Some("variable.parameter")
+        );
+        assert_eq!(
+            highlight_of(&syntax, &text, "alpha", 2),
+            Some("variable.parameter")
+        );
+        assert_ne!(
+            highlight_of(&syntax, &text, "alpha", 3),
+            Some("variable.parameter")
+        );
+    }
+
+    #[test]
+    fn long_parses_continue_in_the_next_frame() {
+        let text = Rope::from_str(&"fn f(a: u32) -> u32 {\n    a + 1\n}\n".repeat(50_000));
+        let mut syntax = Syntax::new(Language::by_injection_name("rust").unwrap()).unwrap();
+        syntax.highlight(&text, 0..100);
+        assert!(syntax.tree().is_none());
+        assert_eq!(syntax.find_highlight(0), None);
+        while syntax.tree().is_none() {
+            syntax.highlight(&text, 0..100);
+        }
+        assert_eq!(syntax.find_highlight(0), Some("keyword"));
+    }
+
+    #[test]
+    fn editing_during_a_long_parse_starts_it_over() {
+        let mut text = Rope::from_str(&"fn f() {}\n".repeat(100_000));
+        let mut syntax = Syntax::new(Language::by_injection_name("rust").unwrap()).unwrap();
+        syntax.highlight(&text, 0..100);
+        assert!(syntax.tree().is_none());
+        let edit = Edit::Insert {
+            char_idx: 0,
+            text: "struct S;\n".to_string(),
+        };
+        edit.apply(&mut text);
+        syntax.edit(&input_edit(&edit, &text));
+        syntax.update(&text);
+        let root = syntax.tree().unwrap().root_node();
+        assert!(!root.has_error());
+        assert_eq!(root.child(0).unwrap().kind(), "struct_item");
+        assert_eq!(root.child_count(), 100_001);
+    }
+
+    /// Applies an edit to `text` and to the syntax, as the buffer does.
+    fn apply(syntax: &mut Syntax, text: &mut Rope, edit: Edit) {
+        edit.apply(text);
+        syntax.edit(&input_edit(&edit, text));
+    }
+
+    #[test]
+    fn edits_are_parsed_and_highlighted_again() {
+        let (mut syntax, mut text) = highlighted(
+            "rust",
+            "fn f() {}
+
+fn g() {}
+",
+        );
+        let old_tree = syntax.tree().unwrap().clone();
+        apply(
+            &mut syntax,
+            &mut text,
+            Edit::Insert {
+                char_idx: 11,
+                text: "pub ".to_string(),
+            },
+        );
+        assert!(syntax.tree().is_none());
+        syntax.highlight(&text, 0..text.len_bytes());
+        let tree = syntax.tree().unwrap();
+        assert_eq!(
+            tree.root_node().child(1).unwrap().child(0).unwrap().kind(),
+            "visibility_modifier"
+        );
+        // Only the second function changed
+        let changed: Vec<_> = old_tree.changed_ranges(tree).collect();
+        assert!(changed.iter().all(|range| range.start_byte >= 11));
+        assert_eq!(highlight_of(&syntax, &text, "pub", 0), Some("keyword"));
+        assert_eq!(highlight_of(&syntax, &text, "fn", 0), Some("keyword"));
+        assert_eq!(highlight_of(&syntax, &text, "fn", 1), Some("keyword"));
+
+        apply(
+            &mut syntax,
+            &mut text,
+            Edit::Remove {
+                char_idx: 0,
+                text: "fn f() {}\n\n".to_string(),
+            },
+        );
+        syntax.highlight(&text, 0..text.len_bytes());
+        assert_eq!(text.to_string(), "pub fn g() {}\n");
+        assert_eq!(syntax.find_highlight(0), Some("keyword"));
+        assert_eq!(highlight_of(&syntax, &text, "g", 0), Some("function"));
+    }
+
+    #[test]
+    fn edited_injections_are_parsed_again() {
+        let (mut syntax, mut text) =
+            highlighted("markdown", "# Title\n\n```rust\nlet x = 1;\n```\n");
+        assert_eq!(highlight_of(&syntax, &text, "let", 0), Some("keyword"));
+        let start = text.to_string().find("let").unwrap();
+        apply(
+            &mut syntax,
+            &mut text,
+            Edit::Insert {
+                char_idx: start,
+                text: "fn f() {}\n".to_string(),
+            },
+        );
+        syntax.highlight(&text, 0..text.len_bytes());
+        assert_eq!(highlight_of(&syntax, &text, "fn", 0), Some("keyword"));
+        assert_eq!(highlight_of(&syntax, &text, "let", 0), Some("keyword"));
+        assert_eq!(syntax.injections.layers.len(), 1);
+        assert!(!syntax.injections.layers[0].edited);
+    }
+
+    #[test]
+    fn local_predicates() {
+        let query = Query::new(
+            tree_sitter_rust::language(),
+            "((identifier) @a (#is-not? local)) ((identifier) @b (#is? local)) (identifier) @c",
+        )
+        .unwrap();
+        assert!(applies_to_locals(&query, 0, false));
+        assert!(!applies_to_locals(&query, 0, true));
+        assert!(!applies_to_locals(&query, 1, false));
+        assert!(applies_to_locals(&query, 1, true));
+        assert!(applies_to_locals(&query, 2, true));
+    }
+}
@@ -0,0 +1,35 @@
+use std::path::{Path, PathBuf};
+
+use super::layout::PaneLayout;
+
+/// A tab page, which arranges the windows it shows in its own layout of panes.
+pub struct TabPage {
+    pub panes: PaneLayout,
+    /// The working directory set with `:tcd`, relative paths typed in this tab are resolved
+    /// against it instead of the directory ted was started in
+    pub cwd: Option<PathBuf>,
+}
+
+impl TabPage {
+    pub fn new(window_uuid: usize, cwd: Option<PathBuf>) -> Self {
+        TabPage {
+            panes: PaneLayout::new(window_uuid),
+            cwd,
+        }
+    }
+
+    /// Returns `path` relative to the working directory of the tab.
+    pub fn resolve_path(&self, path: &str) -> String {
+        match &self.cwd {
+            Some(cwd) if Path::new(path).is_relative() => cwd.join(path).display().to_string(),
+            _ => path.to_string(),
+        }
+    }
+
+    /// Returns the directory searched by `:grep` and the file finder.
+    pub fn directory(&self) -> String {
+        self.cwd
+            .as_ref()
+            .map_or(".".to_string(), |cwd| cwd.display().to_string())
+    }
+}
@@ -0,0 +1,316 @@
+use std::{collections::HashMap, env, fs, io::ErrorKind, str::FromStr};
+
+use ratatui::style::{Color, Modifier, Style};
+use toml::{Table, Value};
+
+use crate::{config, log::Severity};
+
+/// The themes that come with ted, user themes in `<config dir>/themes` take precedence.
+const BUNDLED_THEMES: &[(&str, &str)] = &[
+    ("default", include_str!("../../themes/default.toml")),
+    ("light", include_str!("../../themes/light.toml")),
+    ("plain", include_str!("../../themes/plain.toml")),
+];
+
+/// The parts of the interface a theme can style.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub enum UiElement {
+    LineNumber,
+    CurrentLineNumber,
+    CurrentLine,
+    Selection,
+    SearchMatch,
+    FocusedBorder,
+    StatusBar,
+    PendingKeys,
+    CommandLine,
+    TabBar,
+    SelectedTab,
+    DialogBorder,
+    DialogTitle,
+    DialogSelection,
+    /// The chars of a fuzzy match
+    DialogMatch,
+    /// Register names, line numbers of search results and the keys of bindings
+    DialogLabel,
+    /// Languages of windows and the modes of bindings
+    DialogDetail,
+    DialogPath,
+    Modified,
+    LogInfo,
+    LogWarning,
+    LogError,
+}
+
+impl UiElement {
+    pub const ALL: [UiElement; 22] = [
+        UiElement::LineNumber,
+        UiElement::CurrentLineNumber,
+        UiElement::CurrentLine,
+        UiElement::Selection,
+        UiElement::SearchMatch,
+        UiElement::FocusedBorder,
+        UiElement::StatusBar,
+        UiElement::PendingKeys,
+        UiElement::CommandLine,
+        UiElement::TabBar,
+        UiElement::SelectedTab,
+        UiElement::DialogBorder,
+        UiElement::DialogTitle,
+        UiElement::DialogSelection,
+        UiElement::DialogMatch,
+        UiElement::DialogLabel,
+        UiElement::DialogDetail,
+        UiElement::DialogPath,
+        UiElement::Modified,
+        UiElement::LogInfo,
+        UiElement::LogWarning,
+        UiElement::LogError,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            UiElement::LineNumber => "line_number",
+            UiElement::CurrentLineNumber => "current_line_number",
+            UiElement::CurrentLine => "current_line",
+            UiElement::Selection => "selection",
+            UiElement::SearchMatch => "search_match",
+            UiElement::FocusedBorder => "focused_border",
+            UiElement::StatusBar => "status_bar",
+            UiElement::PendingKeys => "pending_keys",
+            UiElement::CommandLine => "command_line",
+            UiElement::TabBar => "tab_bar",
+            UiElement::SelectedTab => "selected_tab",
+            UiElement::DialogBorder => "dialog_border",
+            UiElement::DialogTitle => "dialog_title",
+            UiElement::DialogSelection => "dialog_selection",
+            UiElement::DialogMatch => "dialog_match",
+            UiElement::DialogLabel => "dialog_label",
+            UiElement::DialogDetail => "dialog_detail",
+            UiElement::DialogPath => "dialog_path",
+            UiElement::Modified => "modified",
+            UiElement::LogInfo => "log_info",
+            UiElement::LogWarning => "log_warning",
+            UiElement::LogError => "log_error",
+        }
+    }
+
+    fn by_name(name: &str) -> Option<UiElement> {
+        UiElement::ALL
+            .into_iter()
+            .find(|element| element.name() == name)
+    }
+}
+
+/// The styles of tree-sitter captures and of the interface, read from a TOML file with a
+/// `[syntax]` table keyed by capture names and a `[ui]` table keyed by `UiElement` names.
+/// Captures without a style use the style of their parent, `function.method` that of `function`.
+/// A style is either a colour or a table with `fg`, `bg`, `bold`, `italic` and `underline`.
+pub struct Theme {
+    pub name: String,
+    syntax: HashMap<String, Style>,
+    ui: HashMap<UiElement, Style>,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        let (name, content) = BUNDLED_THEMES[0];
+        let mut theme = Theme::parse(name, content, None).expect("The default theme is valid");
+        if !supports_truecolor() {
+            theme.reduce_to_ansi_colors();
+        }
+        theme
+    }
+}
+
+impl Theme {
+    /// Loads the theme called `name` from the config directory or the bundled themes. The UI
+    /// elements it leaves out look like in the default theme.
+    pub fn load(name: &str) -> Result<Theme, String> {
+        let user_file =
+            config::config_dir().map(|dir| dir.join("themes").join(format!("{name}.toml")));
+        let content = match user_file.map(fs::read_to_string) {
+            Some(Ok(content)) => content,
+            Some(Err(e)) if e.kind() != ErrorKind::NotFound => {
+                return Err(format!("Could not read theme {name}: {e}"));
+            }
+            _ => BUNDLED_THEMES
+                .iter()
+                .find(|(bundled, _)| *bundled == name)
+                .map(|(_, content)| content.to_string())
+                .ok_or_else(|| format!("Unknown theme {name}"))?,
+        };
+        let mut theme = Theme::parse(name, &content, Some(&Theme::default()))?;
+        if !supports_truecolor() {
+            theme.reduce_to_ansi_colors();
+        }
+        Ok(theme)
+    }
+
+    fn parse(name: &str, content: &str, fallback: Option<&Theme>) -> Result<Theme, String> {
+        let table = content
+            .parse::<Table>()
+            .map_err(|e| format!("Could not parse theme {name}: {e}"))?;
+        let mut theme = Theme {
+            name: name.to_string(),
+            syntax: HashMap::new(),
+            ui: fallback.map(|theme| theme.ui.clone()).unwrap_or_default(),
+        };
+        for (section, value) in &table {
+            let Value::Table(styles) = value else {
+                return Err(format!("{section} should be a table in theme {name}"));
+            };
+            for (key, style) in styles {
+                let style = parse_style(style).map_err(|e| format!("{section}.{key}: {e}"))?;
+                match section.as_str() {
+                    "syntax" => {
+                        theme.syntax.insert(key.clone(), style);
+                    }
+                    "ui" => {
+                        let element = UiElement::by_name(key)
+                            .ok_or_else(|| format!("Unknown UI element {key} in theme {name}"))?;
+                        theme.ui.insert(element, style);
+                    }
+                    _ => return Err(format!("Unknown section {section} in theme {name}")),
+                }
+            }
+        }
+        Ok(theme)
+    }
+
+    pub fn ui(&self, element: UiElement) -> Style {
+        self.ui.get(&element).copied().unwrap_or_default()
+    }
+
+    pub fn log_style(&self, severity: Severity) -> Style {
+        self.ui(match severity {
+            Severity::Info => UiElement::LogInfo,
+            Severity::Warning => UiElement::LogWarning,
+            Severity::Error => UiElement::LogError,
+        })
+    }
+
+    /// Returns the style of a tree-sitter capture like `keyword.return`, or the one of the
+    /// closest parent capture that has one.
+    pub fn syntax(&self, capture: &str) -> Option<Style> {
+        let mut capture = capture;
+        loop {
+            if let Some(style) = self.syntax.get(capture) {
+                return Some(*style);
+            }
+            capture = capture.rsplit_once('.')?.0;
+        }
+    }
+
+    /// Replaces RGB colours with the closest of the 16 ANSI colours.
+    fn reduce_to_ansi_colors(&mut self) {
+        for style in self.syntax.values_mut().chain(self.ui.values_mut()) {
+            style.fg = style.fg.map(closest_ansi_color);
+            style.bg = style.bg.map(closest_ansi_color);
+        }
+    }
+}
+
+/// Returns the names of the bundled themes and the ones in the config directory.
+pub fn theme_names() -> Vec<String> {
+    let mut names: Vec<String> = BUNDLED_THEMES
+        .iter()
+        .map(|(name, _)| name.to_string())
+        .collect();
+    if let Some(Ok(entries)) = config::config_dir().map(|dir| fs::read_dir(dir.join("themes"))) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path
+                .extension()
+                .is_some_and(|extension| extension == "toml")
+            {
+                if let Some(stem) = path.file_stem() {
+                    names.push(stem.to_string_lossy().to_string());
+                }
+            }
+        }
+    }
+    names.sort();
+    names.dedup();
+    names
+}
+
+fn parse_style(value: &Value) -> Result<Style, String> {
+    match value {
+        Value::String(color) => Ok(Style::new().fg(parse_color(color)?)),
+        Value::Table(table) => {
+            let mut style = Style::new();
+            for (key, value) in table {
+                match (key.as_str(), value) {
+                    ("fg", Value::String(color)) => style = style.fg(parse_color(color)?),
+                    ("bg", Value::String(color)) => style = style.bg(parse_color(color)?),
+                    (attribute, Value::Boolean(enabled)) => {
+                        let modifier = match attribute {
+                            "bold" => Modifier::BOLD,
+                            "italic" => Modifier::ITALIC,
+                            "underline" => Modifier::UNDERLINED,
+                            "dim" => Modifier::DIM,
+                            "reversed" => Modifier::REVERSED,
+                            "strikethrough" => Modifier::CROSSED_OUT,
+                            _ => return Err(format!("Unknown attribute {attribute}")),
+                        };
+                        style = if *enabled {
+                            style.add_modifier(modifier)
+                        } else {
+                            style.remove_modifier(modifier)
+                        };
+                    }
+                    _ => return Err(format!("Invalid {key} = {value}")),
+                }
+            }
+            Ok(style)
+        }
+        _ => Err(format!("Invalid style {value}")),
+    }
+}
+
+fn parse_color(color: &str) -> Result<Color, String> {
+    Color::from_str(color).map_err(|_| format!("Invalid colour {color}"))
+}
+
+/// Whether the terminal claims to show 24 bit colours.
+fn supports_truecolor() -> bool {
+    env::var("COLORTERM").is_ok_and(|value| value == "truecolor" || value == "24bit")
+}
+
+/// The 16 ANSI colours with the RGB values xterm uses for them
+const ANSI_COLORS: [(Color, (u8, u8, u8)); 16] = [
+    (Color::Black, (0, 0, 0)),
+    (Color::Red, (205, 0, 0)),
+    (Color::Green, (0, 205, 0)),
+    (Color::Yellow, (205, 205, 0)),
+    (Color::Blue, (0, 0, 238)),
+    (Color::Magenta, (205, 0, 205)),
+    (Color::Cyan, (0, 205, 205)),
+    (Color::Gray, (229, 229, 229)),
+    (Color::DarkGray, (127, 127, 127)),
+    (Color::LightRed, (255, 0, 0)),
+    (Color::LightGreen, (0, 255, 0)),
+    (Color::LightYellow, (255, 255, 0)),
+    (Color::LightBlue, (92, 92, 255)),
+    (Color::LightMagenta, (255, 0, 255)),
+    (Color::LightCyan, (0, 255, 255)),
+    (Color::White, (255, 255, 255)),
+];
+
+fn closest_ansi_color(color: Color) -> Color {
+    let Color::Rgb(r, g, b) = color else {
+        return color;
+    };
+    let distance = |(ar, ag, ab): (u8, u8, u8)| {
+        let dr = r as i32 - ar as i32;
+        let dg = g as i32 - ag as i32;
+        let db = b as i32 - ab as i32;
+        dr * dr + dg * dg + db * db
+    };
+    ANSI_COLORS
+        .iter()
+        .min_by_key(|(_, rgb)| distance(*rgb))
+        .map(|(ansi, _)| *ansi)
+        .unwrap_or(color)
+}
@@ -1,65 +1,78 @@
-use std::cmp::Ordering;
+use std::{
+    cell::{Ref, RefCell, RefMut},
+    rc::Rc,
+    time::Instant,
+};
 
 use ratatui::{
     layout::Rect,
-    style::{Color, Style, Stylize},
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
     Frame,
 };
 use ropey::Rope;
+use tree_sitter::Tree;
 
 use super::{
-    language::{get_highlight_color, Language},
-    COMMAND_MODE_BACKGROUND,
+    app::VisualKind,
+    buffer::Buffer,
+    language::Language,
+    options::Options,
+    search::SearchMatches,
+    theme::{Theme, UiElement},
 };
 
+/// A view of a buffer with its own cursor and scroll position. Several windows can show
+/// the same buffer, edits made in one of them show up in the others.
 pub struct Window {
     pub uuid: usize,
-    pub ident: Option<String>,
-    pub text: Rope,
+    pub buffer: Rc<RefCell<Buffer>>,
+    /// The screen column the text is scrolled to
     pub scroll_x: usize,
     pub scroll_y: usize,
     pub cursor_char_index: usize,
-    pub attached_file_path: Option<String>,
-    pub modified: bool,
-    pub language: Option<Language>,
-    pub highlight_data: Option<HighlightData>,
+    pub selection_anchor: usize,
+    /// The visual column `j` and `k` try to keep, set by the first of a series of vertical moves
+    pub desired_column: Option<usize>,
+    pub search_matches: Option<SearchMatches>,
+    /// The char indices of the start and end of the last visual selection, `'<` and `'>`
+    pub visual_marks: Option<(usize, usize)>,
+    /// When the window was last selected, the window picker lists recently used windows first
+    pub last_used: Instant,
+    /// The `text_version` of the buffer the cursor and marks are placed in
+    seen_version: usize,
 }
 
-pub struct HighlightJob {
-    pub text: Rope,
-    pub window_uuid: usize,
-    pub language: Language,
+/// A selected region of text. Blockwise selections consist of one char range per line.
+pub struct Selection {
+    pub kind: VisualKind,
+    pub ranges: Vec<std::ops::Range<usize>>,
 }
 
-type ByteRangeHighlightData = (usize, std::ops::Range<usize>, &'static str);
-
-pub struct HighlightJobResult {
-    pub window_uuid: usize,
-    pub highlights: Vec<ByteRangeHighlightData>,
-}
+impl Selection {
+    pub fn contains(&self, char_index: usize) -> bool {
+        self.ranges.iter().any(|r| r.contains(&char_index))
+    }
 
-pub struct HighlightData {
-    highlights: Vec<ByteRangeHighlightData>,
+    pub fn start(&self) -> usize {
+        self.ranges.first().map(|r| r.start).unwrap_or(0)
+    }
 }
 
-impl HighlightData {
-    pub fn new(highlights: Vec<ByteRangeHighlightData>) -> Self {
-        Self { highlights }
+/// Returns how many columns `c` occupies when it starts at visual column `column`.
+pub fn char_display_width(c: char, column: usize, tab_width: usize) -> usize {
+    match c {
+        '\t' => tab_width - column % tab_width.max(1),
+        c => unicode_width::UnicodeWidthChar::width(c).unwrap_or(1),
     }
+}
 
-    pub fn find_highlight(&self, byte_index: usize) -> Option<&'static str> {
-        match self.highlights.binary_search_by(|(start, range, _)| {
-            if range.contains(&byte_index) {
-                Ordering::Equal
-            } else {
-                start.cmp(&byte_index)
-            }
-        }) {
-            Ok(pos) => Some(self.highlights[pos].2),
-            Err(_) => None,
-        }
+/// Returns how many columns the line numbers and the space after them take up.
+fn gutter_width(options: &Options, max_lines: u32) -> usize {
+    if options.number {
+        max_lines as usize + 1
+    } else {
+        0
     }
 }
 
@@ -72,133 +85,414 @@ fn visual_length_of_number(i: usize) -> u32 {
 }
 
 impl Window {
-    pub fn try_detect_langauge(&mut self) -> Option<&Language> {
-        if let Some(attached_path) = &self.attached_file_path {
-            if let Some(language) = Language::by_file_name(attached_path) {
-                self.language = Some(language);
+    pub fn new(uuid: usize, buffer: Rc<RefCell<Buffer>>) -> Self {
+        let seen_version = buffer.borrow().text_version;
+        Window {
+            uuid,
+            buffer,
+            scroll_x: 0,
+            scroll_y: 0,
+            cursor_char_index: 0,
+            selection_anchor: 0,
+            desired_column: None,
+            search_matches: None,
+            visual_marks: None,
+            last_used: Instant::now(),
+            seen_version,
+        }
+    }
+
+    pub fn buffer(&self) -> Ref<'_, Buffer> {
+        self.buffer.borrow()
+    }
+
+    pub fn buffer_mut(&self) -> RefMut<'_, Buffer> {
+        self.buffer.borrow_mut()
+    }
+
+    /// Returns the text of the buffer. Ropes share their content, so this is cheap, but the
+    /// returned rope doesn't see later edits.
+    pub fn text(&self) -> Rope {
+        self.buffer().text.clone()
+    }
+
+    pub fn tab_width(&self) -> usize {
+        self.buffer().options.tabstop
+    }
+
+    /// Returns whether another window shows the buffer of this one.
+    pub fn shares_buffer(&self) -> bool {
+        Rc::strong_count(&self.buffer) > 1
+    }
+
+    /// Returns whether closing this window loses unsaved changes, which it doesn't while
+    /// another window shows the buffer.
+    pub fn closing_loses_changes(&self) -> bool {
+        self.buffer().modified && !self.shares_buffer()
+    }
+
+    pub fn resolve_title(&self) -> String {
+        self.buffer().resolve_title().to_string()
+    }
+
+    pub fn try_detect_langauge(&mut self) -> Option<Language> {
+        self.buffer_mut().try_detect_langauge().copied()
+    }
+
+    /// Moves the cursor, the selection anchor and the visual marks along with the edits other
+    /// windows made to the buffer since this window last looked at it.
+    pub fn follow_buffer(&mut self) {
+        let buffer = self.buffer.borrow();
+        if self.seen_version == buffer.text_version {
+            return;
+        }
+        match buffer.edits_since(self.seen_version) {
+            Some(edits) => {
+                for edit in edits {
+                    let shift = |p: usize| edit.shift_position(p).unwrap_or(edit.char_idx());
+                    self.cursor_char_index = shift(self.cursor_char_index);
+                    self.selection_anchor = shift(self.selection_anchor);
+                    self.visual_marks = self
+                        .visual_marks
+                        .map(|(start, end)| (shift(start), shift(end)));
+                }
             }
+            None => self.visual_marks = None,
         }
-        self.language.as_ref()
+        let len = buffer.text.len_chars();
+        self.cursor_char_index = self.cursor_char_index.min(len.max(1) - 1);
+        self.selection_anchor = self.selection_anchor.min(len);
+        self.scroll_y = self.scroll_y.min(buffer.text.len_lines() - 1);
+        self.seen_version = buffer.text_version;
+    }
+
+    /// Takes note of an edit made through this window, which already placed its cursor.
+    fn edited(&mut self) {
+        self.desired_column = None;
+        let text_version = self.buffer().text_version;
+        self.seen_version = text_version;
+    }
+
+    pub fn insert(&mut self, char_idx: usize, text: &str) {
+        self.follow_buffer();
+        self.buffer_mut().insert(char_idx, text);
+        self.edited();
+    }
+
+    pub fn remove(&mut self, range: std::ops::Range<usize>) {
+        self.follow_buffer();
+        self.buffer_mut().remove(range);
+        self.edited();
+    }
+
+    /// Replaces the whole text as a single undoable change.
+    pub fn replace_text(&mut self, text: &str) {
+        if self.buffer().text == text {
+            return;
+        }
+        self.begin_change();
+        let len = self.buffer().text.len_chars();
+        self.remove(0..len);
+        self.insert(0, text);
+        self.end_change();
+    }
+
+    pub fn begin_change(&mut self) {
+        self.buffer_mut().history.begin_change();
+    }
+
+    pub fn end_change(&mut self) {
+        self.buffer_mut().history.end_change();
+    }
+
+    pub fn undo(&mut self) -> bool {
+        self.follow_buffer();
+        let cursor = self.buffer_mut().undo();
+        self.restore_cursor(cursor)
     }
 
-    pub fn resolve_title(&self) -> &str {
-        self.ident
+    pub fn redo(&mut self) -> bool {
+        self.follow_buffer();
+        let cursor = self.buffer_mut().redo();
+        self.restore_cursor(cursor)
+    }
+
+    fn restore_cursor(&mut self, cursor: Option<usize>) -> bool {
+        let Some(cursor) = cursor else {
+            return false;
+        };
+        let len = self.buffer().text.len_chars();
+        self.cursor_char_index = cursor.min(len.max(1) - 1);
+        self.edited();
+        true
+    }
+
+    /// Returns the matches of `pattern`, only searching the text again if it or the pattern
+    /// changed since the last call.
+    pub fn search_matches(&mut self, pattern: &str) -> &SearchMatches {
+        let buffer = self.buffer.borrow();
+        if !self
+            .search_matches
             .as_ref()
-            .or(self.attached_file_path.as_ref())
-            .map(|s| s.as_str())
-            .unwrap_or("Untitled")
+            .is_some_and(|m| m.is_current(buffer.text_version, pattern))
+        {
+            self.search_matches = None;
+        }
+        self.search_matches
+            .get_or_insert_with(|| SearchMatches::find(&buffer.text, buffer.text_version, pattern))
+    }
+
+    pub fn syntax_tree(&self) -> Option<Tree> {
+        self.buffer_mut().syntax_tree()
+    }
+
+    /// Returns the visual column of `char_index` within its line, with tabs expanded.
+    pub fn visual_column(&self, char_index: usize) -> usize {
+        let buffer = self.buffer();
+        let line_start = buffer
+            .text
+            .line_to_char(buffer.text.char_to_line(char_index));
+        buffer
+            .text
+            .slice(line_start..char_index)
+            .chars()
+            .fold(0, |column, c| {
+                column + char_display_width(c, column, buffer.options.tabstop)
+            })
+    }
+
+    /// Returns the chars of `line_index` that lie between the visual columns `left` and `right`.
+    fn chars_in_columns(
+        &self,
+        line_index: usize,
+        left: usize,
+        right: usize,
+    ) -> std::ops::Range<usize> {
+        let buffer = self.buffer();
+        let line_start = buffer.text.line_to_char(line_index);
+        let mut start = None;
+        let mut end = line_start;
+        let mut column = 0;
+        for (i, c) in buffer.text.line(line_index).chars().enumerate() {
+            if c == '\n' || column > right {
+                break;
+            }
+            let width = char_display_width(c, column, buffer.options.tabstop);
+            if start.is_none() && column + width > left {
+                start = Some(line_start + i);
+            }
+            column += width;
+            end = line_start + i + 1;
+        }
+        match start {
+            Some(start) => start..end,
+            None => end..end,
+        }
+    }
+
+    pub fn selection(&self, kind: VisualKind) -> Selection {
+        let text = self.text();
+        let len = text.len_chars();
+        let anchor = self.selection_anchor.min(len.max(1) - 1);
+        let start = anchor.min(self.cursor_char_index);
+        let end = anchor.max(self.cursor_char_index);
+        let first_line = text.char_to_line(start);
+        let last_line = text.char_to_line(end);
+        let ranges = match kind {
+            VisualKind::Char => {
+                let range = start..(end + 1).min(len);
+                vec![range]
+            }
+            VisualKind::Line => {
+                let range = text.line_to_char(first_line)..text.line_to_char(last_line + 1);
+                vec![range]
+            }
+            VisualKind::Block => {
+                let anchor_column = self.visual_column(anchor);
+                let cursor_column = self.visual_column(self.cursor_char_index);
+                let left = anchor_column.min(cursor_column);
+                let right = anchor_column.max(cursor_column);
+                (first_line..=last_line)
+                    .map(|line_index| self.chars_in_columns(line_index, left, right))
+                    .collect()
+            }
+        };
+        Selection { kind, ranges }
+    }
+
+    /// The text the Tab key inserts at the cursor.
+    pub fn indent_unit(&self) -> String {
+        if self.buffer().options.expandtab {
+            let column = self.visual_column(self.cursor_char_index);
+            " ".repeat(char_display_width('\t', column, self.tab_width()))
+        } else {
+            "\t".to_string()
+        }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn render(
         &mut self,
         terminal: &mut Frame<'_>,
         layout_rect: Rect,
+        options: &Options,
+        theme: &Theme,
         highlight_line_number: bool,
+        selection: Option<&Selection>,
+        search_pattern: Option<&str>,
+        focused: bool,
     ) {
-        if layout_rect.height < 2 {
+        // Hold on to the buffer through a clone of the pointer, so self stays free to change
+        let buffer_rc = Rc::clone(&self.buffer);
+        let buffer = buffer_rc.borrow();
+        let max_lines = visual_length_of_number(buffer.text.len_lines());
+        if layout_rect.height < 3 || (layout_rect.width as usize) < max_lines as usize + 5 {
             return;
         }
-        let max_lines = visual_length_of_number(self.text.len_lines());
-        let current_line_index = self.text.char_to_line(self.cursor_char_index);
-        let max_line_seen = self.scroll_y + layout_rect.height as usize - 3;
-
-        if current_line_index < self.scroll_y {
-            let offset = self.scroll_y - current_line_index;
-            self.scroll_y -= offset;
+        let search_matches = match search_pattern {
+            Some(pattern) => {
+                self.search_matches(pattern);
+                self.search_matches.as_ref()
+            }
+            None => None,
+        };
+        let current_line_index = buffer.text.char_to_line(self.cursor_char_index);
+        let text_height = layout_rect.height as usize - 2;
+        let scrolloff = options.scrolloff.min((text_height - 1) / 2);
+
+        if current_line_index < self.scroll_y + scrolloff {
+            self.scroll_y = current_line_index.saturating_sub(scrolloff);
         }
 
-        if current_line_index > max_line_seen {
-            self.scroll_y += current_line_index - max_line_seen;
+        // The lines below the cursor only need to be visible as far as they exist
+        let lowest_line = (current_line_index + scrolloff).min(buffer.text.len_lines() - 1);
+        if lowest_line >= self.scroll_y + text_height {
+            self.scroll_y = lowest_line + 1 - text_height;
         }
 
-        let current_line_start = self.text.line_to_char(current_line_index);
-        let line_offset = self.cursor_char_index - current_line_start;
+        // Horizontal scrolling is in screen columns, which tabs and wide chars take several of
+        let cursor_column = self.visual_column(self.cursor_char_index);
+        let cursor_width = char_display_width(
+            buffer.text.get_char(self.cursor_char_index).unwrap_or(' '),
+            cursor_column,
+            buffer.options.tabstop,
+        );
 
-        let l = layout_rect.width as usize - max_lines as usize - 1 - 3;
+        let text_width = layout_rect.width as usize - gutter_width(options, max_lines) - 2;
+        let sidescrolloff = options.sidescrolloff.min((text_width - 1) / 2);
 
-        if line_offset > l + self.scroll_x {
-            self.scroll_x += line_offset - l - self.scroll_x;
+        if cursor_column + cursor_width + sidescrolloff > self.scroll_x + text_width {
+            self.scroll_x = cursor_column + cursor_width + sidescrolloff - text_width;
         }
 
-        if line_offset < self.scroll_x {
-            let offset = self.scroll_x - line_offset;
-            self.scroll_x -= offset;
+        if cursor_column < self.scroll_x + sidescrolloff {
+            self.scroll_x = cursor_column.saturating_sub(sidescrolloff);
         }
 
-        let v = self
+        drop(buffer);
+        buffer_rc
+            .borrow_mut()
+            .highlight(self.scroll_y..self.scroll_y + text_height);
+        let buffer = buffer_rc.borrow();
+
+        let v = buffer
             .text
             .lines_at(self.scroll_y)
             .enumerate()
             .take(layout_rect.height as usize)
             .fold(Vec::new(), |mut acc, (o_idx, element)| {
-                let idx = o_idx + self.scroll_y;
-                let mut line_buf = String::with_capacity(max_lines as usize + 1);
-
-                let idx = idx + 1;
-                for _ in 0..(max_lines - visual_length_of_number(idx)) {
-                    line_buf.push(' ');
-                }
+                let is_current_line = highlight_line_number
+                    && o_idx + self.scroll_y == buffer.text.char_to_line(self.cursor_char_index);
+                let mut spans = Vec::new();
+                if options.number {
+                    let idx = o_idx + self.scroll_y;
+                    let mut line_buf = String::with_capacity(max_lines as usize + 1);
 
-                line_buf.push_str(&idx.to_string());
-                let mut line_span = Span::styled(line_buf, Style::new().fg(Color::Yellow));
+                    let idx = idx + 1;
+                    for _ in 0..(max_lines - visual_length_of_number(idx)) {
+                        line_buf.push(' ');
+                    }
 
-                if highlight_line_number
-                    && o_idx + self.scroll_y == self.text.char_to_line(self.cursor_char_index)
-                {
-                    line_span = line_span.bg(COMMAND_MODE_BACKGROUND);
+                    line_buf.push_str(&idx.to_string());
+                    let style = if is_current_line {
+                        theme.ui(UiElement::CurrentLineNumber)
+                    } else {
+                        theme.ui(UiElement::LineNumber)
+                    };
+                    spans.push(Span::styled(line_buf, style));
                 }
 
-                if self.scroll_x >= element.len_chars() {
-                    acc.push(Line::from(line_span));
-                    return acc;
+                if options.number {
+                    spans.push(Span::from(" "));
                 }
 
-                let mut spans = Vec::new();
-                spans.push(line_span);
-                spans.push(Span::from(" "));
-
-                let start_of_current_line = self.text.line_to_char(o_idx + self.scroll_y);
+                let start_of_current_line = buffer.text.line_to_char(o_idx + self.scroll_y);
 
+                let mut column = 0;
                 for (i, c) in element.chars().enumerate() {
+                    let start_column = column;
+                    column += char_display_width(c, column, buffer.options.tabstop);
+                    if column <= self.scroll_x {
+                        continue;
+                    }
+                    let is_selected =
+                        selection.is_some_and(|s| s.contains(start_of_current_line + i));
+                    // Only the part of a tab or wide char right of the scroll position is shown
+                    let visible_width = column - start_column.max(self.scroll_x);
                     let string_to_use = match c {
-                        '\t' => "    ".to_string(), // tab as 4 spaces
+                        '\t' => " ".repeat(visible_width),
+                        _ if start_column < self.scroll_x => " ".repeat(visible_width),
+                        '\n' if is_selected => " ".to_string(),
                         c => c.to_string(),
                     };
 
-                    let byte_index = self
+                    let byte_index = buffer
                         .text
                         .try_char_to_byte(start_of_current_line + i)
                         .expect("Byte not found");
                     let mut span = Span::from(string_to_use);
-                    if let Some(hd) = &self.highlight_data {
-                        if let Some(token) = hd.find_highlight(byte_index) {
-                            if let Some(color) = get_highlight_color(token) {
-                                span = span.fg(color);
-                            }
+                    if is_current_line {
+                        span.patch_style(theme.ui(UiElement::CurrentLine));
+                    }
+                    if let Some(token) = buffer.find_highlight(byte_index) {
+                        if let Some(style) = theme.syntax(token) {
+                            span.patch_style(style);
                         }
                     }
+                    if is_selected {
+                        span.patch_style(theme.ui(UiElement::Selection));
+                    } else if search_matches
+                        .is_some_and(|m| m.index_at(start_of_current_line + i).is_some())
+                    {
+                        span.patch_style(theme.ui(UiElement::SearchMatch));
+                    }
                     spans.push(span);
                 }
 
                 acc.push(Line::from(spans));
                 acc
             });
-        terminal.render_widget(
-            Paragraph::new(v).block(
-                Block::default()
-                    .title(Line::from(format!(
-                        "{}{}",
-                        self.resolve_title(),
-                        if self.modified { "*" } else { "" }
-                    )))
-                    .borders(Borders::all()),
-            ),
-            layout_rect,
-        );
+        let mut block = Block::default()
+            .title(Line::from(format!(
+                "{}{} [{}]",
+                buffer.resolve_title(),
+                if buffer.modified { "*" } else { "" },
+                buffer.file_format
+            )))
+            .borders(Borders::all());
+        if focused {
+            block = block.border_style(theme.ui(UiElement::FocusedBorder));
+        }
+        terminal.render_widget(Paragraph::new(v).block(block), layout_rect);
     }
 
-    pub fn render_cursor(&self, terminal: &mut Frame<'_>, layout_rect: Rect) {
-        let current_line = self.text.char_to_line(self.cursor_char_index);
+    pub fn render_cursor(&self, terminal: &mut Frame<'_>, layout_rect: Rect, options: &Options) {
+        let buffer = self.buffer();
+        let max_lines = visual_length_of_number(buffer.text.len_lines());
+        if layout_rect.height < 3 || (layout_rect.width as usize) < max_lines as usize + 5 {
+            return;
+        }
+        let current_line = buffer.text.char_to_line(self.cursor_char_index);
         if current_line < self.scroll_y {
             return;
         }
@@ -207,25 +501,14 @@ impl Window {
             return;
         }
 
-        let current_line_start = self.text.line_to_char(current_line);
-
-        if self.cursor_char_index - current_line_start < self.scroll_x {
+        let column = self.visual_column(self.cursor_char_index);
+        let text_width = layout_rect.width as usize - gutter_width(options, max_lines) - 2;
+        if column < self.scroll_x || column >= self.scroll_x + text_width {
             return;
         }
 
         let cursor_y = current_line - self.scroll_y + 1;
-        let mut cursor_x = (1 + visual_length_of_number(self.text.len_lines()) + 1) as usize;
-        let mut to_remove = 0;
-        for i in 0..(self.cursor_char_index - current_line_start) {
-            let width =
-                unicode_width::UnicodeWidthChar::width(self.text.char(current_line_start + i))
-                    .unwrap_or(1);
-            if i < self.scroll_x {
-                to_remove += width;
-            }
-            cursor_x += width;
-        }
-        cursor_x -= to_remove;
+        let cursor_x = 1 + gutter_width(options, max_lines) + column - self.scroll_x;
         terminal.set_cursor(
             layout_rect.x + cursor_x as u16,
             layout_rect.y + cursor_y as u16,
@@ -0,0 +1,429 @@
+use crate::frontend::{
+    app::{App, Mode, VisualKind},
+    layout::{PaneDirection, SplitDirection},
+    register::{RegisterContent, RegisterKind},
+    window::{char_display_width, Window},
+};
+
+/// Commands that are neither motions nor operators.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum Action {
+    EnterCommandMode,
+    Insert,
+    Append,
+    OpenLineBelow,
+    OpenLineAbove,
+    DeleteChar,
+    SubstituteChar,
+    PutAfter,
+    PutBefore,
+    Undo,
+    Redo,
+    VisualChar,
+    VisualLine,
+    VisualBlock,
+    ExitVisual,
+    SwapSelectionEnds,
+    NextWindow,
+    PreviousWindow,
+    ShowWindows,
+    /// Goes to the tab given by the count, or the next one without a count
+    NextTab,
+    PreviousTab,
+    FindFile,
+    SplitPane(SplitDirection),
+    FocusPane(PaneDirection),
+    FocusNextPane,
+    ClosePane,
+    OnlyPane,
+    EqualizePanes,
+    /// Grows the focused pane by the count, shrinks it if `grow` is false
+    ResizePane {
+        along: SplitDirection,
+        grow: bool,
+    },
+    SearchForward,
+    SearchBackward,
+    SearchNext,
+    SearchPrevious,
+}
+
+impl Action {
+    pub fn run(&self, app: &mut App, count: Option<usize>, register: Option<char>) {
+        let n = count.unwrap_or(1);
+        match self {
+            Action::EnterCommandMode => {
+                // From visual mode the command works on the selected lines
+                let buffer = match app.current_mode {
+                    Mode::Visual { .. } => "'<,'>".to_string(),
+                    _ => String::new(),
+                };
+                app.current_mode = Mode::Command {
+                    char_idx: buffer.chars().count(),
+                    buffer,
+                }
+            }
+            Action::Insert => {
+                if let Some(sw) = app.selected_window_mut() {
+                    sw.begin_change();
+                    app.current_mode = Mode::Insert;
+                } else {
+                    app.log.log("Error: No open windows");
+                }
+            }
+            Action::Append => {
+                if let Some(sw) = app.selected_window_mut() {
+                    if sw.cursor_char_index < sw.text().len_chars() {
+                        sw.cursor_char_index += 1;
+                    }
+                    sw.begin_change();
+                    app.current_mode = Mode::Insert;
+                }
+            }
+            Action::OpenLineBelow | Action::OpenLineAbove => {
+                if let Some(sw) = app.selected_window_mut() {
+                    let line_index = sw.text().char_to_line(sw.cursor_char_index);
+                    sw.begin_change();
+                    if let Action::OpenLineAbove = self {
+                        let idx = sw.text().line_to_char(line_index);
+                        sw.insert(idx, "\n");
+                        sw.cursor_char_index = sw.text().line_to_char(line_index);
+                    } else {
+                        let text = sw.text();
+                        let idx = text.line_to_char(line_index) + text.line(line_index).len_chars();
+                        sw.insert(idx, "\n");
+                        sw.cursor_char_index = sw.text().line_to_char(line_index + 1);
+                    }
+                    app.current_mode = Mode::Insert;
+                }
+            }
+            Action::DeleteChar => {
+                delete_chars(app, n, register);
+            }
+            Action::SubstituteChar => {
+                if let Some(sw) = app.selected_window_mut() {
+                    sw.begin_change();
+                    if delete_chars(app, n, register) {
+                        app.current_mode = Mode::Insert;
+                    } else if let Some(sw) = app.selected_window_mut() {
+                        sw.end_change();
+                    }
+                }
+            }
+            Action::PutAfter => put(app, register, n, true),
+            Action::PutBefore => put(app, register, n, false),
+            Action::Undo => {
+                // Stops at the oldest change, so a huge count doesn't keep trying
+                (0..n).all(|_| undo(app));
+            }
+            Action::Redo => {
+                (0..n).all(|_| redo(app));
+            }
+            Action::VisualChar => enter_visual_mode(app, VisualKind::Char),
+            Action::VisualLine => enter_visual_mode(app, VisualKind::Line),
+            Action::VisualBlock => enter_visual_mode(app, VisualKind::Block),
+            Action::ExitVisual => app.current_mode = Mode::Normal,
+            Action::SwapSelectionEnds => {
+                if let Some(sw) = app.selected_window_mut() {
+                    std::mem::swap(&mut sw.selection_anchor, &mut sw.cursor_char_index);
+                }
+            }
+            Action::NextWindow => app.next_window(n),
+            Action::PreviousWindow => app.previous_window(n),
+            Action::NextTab => match count {
+                Some(n) if (1..=app.tabs.len()).contains(&n) => app.select_tab(n - 1),
+                Some(_) => app.log.log("Error: No such tab"),
+                None => app.next_tab(),
+            },
+            Action::PreviousTab => app.previous_tab(n),
+            Action::ShowWindows => super::dialog::open_window_picker(app),
+            Action::FindFile => super::dialog::open_file_finder(app),
+            Action::SplitPane(direction) => app.split_pane(*direction),
+            Action::FocusPane(direction) => app.focus_pane_in_direction(*direction),
+            Action::FocusNextPane => app.focus_next_pane(),
+            Action::ClosePane => {
+                if !app.close_pane() {
+                    app.log.log("Error: Cannot close the last pane");
+                }
+            }
+            Action::OnlyPane => app.only_pane(),
+            Action::EqualizePanes => app.tab_mut().panes.equalize(),
+            Action::ResizePane { along, grow } => {
+                let delta = n as i32;
+                app.tab_mut()
+                    .panes
+                    .resize_focused(*along, if *grow { delta } else { -delta });
+            }
+            Action::SearchForward => super::search::start_search(app, false),
+            Action::SearchBackward => super::search::start_search(app, true),
+            Action::SearchNext => super::search::search_next(app, n, false),
+            Action::SearchPrevious => super::search::search_next(app, n, true),
+        }
+    }
+}
+
+/// Enters visual mode, or switches the kind of selection if already in visual mode.
+/// Choosing the current kind again leaves visual mode.
+fn enter_visual_mode(app: &mut App, kind: VisualKind) {
+    if let Mode::Visual { kind: current } = app.current_mode {
+        app.current_mode = if current == kind {
+            Mode::Normal
+        } else {
+            Mode::Visual { kind }
+        };
+    } else if let Some(sw) = app.selected_window_mut() {
+        sw.selection_anchor = sw.cursor_char_index;
+        app.current_mode = Mode::Visual { kind };
+    } else {
+        app.log.log("Error: No open windows");
+    }
+}
+
+/// Deletes up to `count` chars of the current line into `register`, starting at the cursor.
+/// Returns whether anything was deleted.
+fn delete_chars(app: &mut App, count: usize, register: Option<char>) -> bool {
+    let Some(sw) = app.edit_windows.get_mut(app.selected_window) else {
+        return false;
+    };
+    let len = sw.text().len_chars();
+    let start = sw.cursor_char_index;
+    if len == 0 || start >= len {
+        return false;
+    }
+    let mut end = start;
+    while end < len && end - start < count && (end == start || sw.text().char(end) != '\n') {
+        end += 1;
+    }
+    let deleted = sw.text().slice(start..end).to_string();
+    sw.remove(start..end);
+    app.registers.delete(
+        register,
+        RegisterContent::new(deleted, RegisterKind::Charwise),
+    );
+    true
+}
+
+/// Inserts the content of `register` `count` times after or before the cursor. Linewise
+/// content goes below or above the current line instead, blockwise content goes into the
+/// lines from the cursor down at the cursor's column.
+fn put(app: &mut App, register: Option<char>, count: usize, after: bool) {
+    let Some(content) = app.registers.get(register).cloned() else {
+        app.log.log("Error: Register is empty");
+        return;
+    };
+    let Some(sw) = app.edit_windows.get_mut(app.selected_window) else {
+        app.log.log("Error: No open windows");
+        return;
+    };
+    let text = content.text.repeat(count);
+    let len = sw.text().len_chars();
+    match content.kind {
+        RegisterKind::Charwise => {
+            let mut idx = sw.cursor_char_index.min(len);
+            if after && idx < len && sw.text().char(idx) != '\n' {
+                idx += 1;
+            }
+            sw.insert(idx, &text);
+            sw.cursor_char_index = idx + text.chars().count().max(1) - 1;
+        }
+        RegisterKind::Linewise => {
+            let line_index = sw.text().char_to_line(sw.cursor_char_index.min(len));
+            let idx = sw
+                .text()
+                .line_to_char(if after { line_index + 1 } else { line_index });
+            if idx == len && len > 0 && sw.text().char(len - 1) != '\n' {
+                // The last line has no newline to put the text after.
+                let text = text.strip_suffix('\n').unwrap_or(&text);
+                sw.insert(idx, &format!("\n{text}"));
+                sw.cursor_char_index = idx + 1;
+            } else {
+                sw.insert(idx, &text);
+                sw.cursor_char_index = idx;
+            }
+        }
+        RegisterKind::Blockwise => put_block(sw, &content.text, count, after),
+    }
+    sw.cursor_char_index = sw.cursor_char_index.min(sw.text().len_chars().max(1) - 1);
+}
+
+/// Puts each line of `block`, padded to the width of the block and repeated `count` times,
+/// into consecutive lines at the visual column of the cursor, adding lines at the end of the
+/// text where needed.
+fn put_block(sw: &mut Window, block: &str, count: usize, after: bool) {
+    let tab_width = sw.tab_width();
+    let width_of = |line: &str| {
+        line.chars().fold(0, |column, c| {
+            column + char_display_width(c, column, tab_width)
+        })
+    };
+    let lines: Vec<&str> = block.split('\n').collect();
+    let block_width = lines.iter().map(|line| width_of(line)).max().unwrap_or(0);
+
+    let len = sw.text().len_chars();
+    let cursor = sw.cursor_char_index.min(len);
+    let mut column = sw.visual_column(cursor);
+    if after {
+        if let Some(c) = sw.text().get_char(cursor).filter(|c| *c != '\n') {
+            column += char_display_width(c, column, tab_width);
+        }
+    }
+    let first_line = sw.text().char_to_line(cursor);
+
+    sw.begin_change();
+    for (i, line) in lines.iter().enumerate() {
+        let padded = format!("{line}{}", " ".repeat(block_width - width_of(line)));
+        let piece = padded.repeat(count);
+        let line_index = first_line + i;
+        let text_len = sw.text().len_chars();
+        let ends_with_newline = text_len > 0 && sw.text().char(text_len - 1) == '\n';
+        let line_count = sw.text().len_lines() - usize::from(ends_with_newline);
+        if line_index >= line_count.max(1) {
+            let new_line = format!("{}{piece}", " ".repeat(column));
+            let new_line = new_line.trim_end_matches(' ');
+            if ends_with_newline || text_len == 0 {
+                sw.insert(text_len, &format!("{new_line}\n"));
+            } else {
+                sw.insert(text_len, &format!("\n{new_line}"));
+            }
+            continue;
+        }
+        let (idx, line_column) = char_at_column(sw, line_index, column);
+        let at_line_end = matches!(sw.text().get_char(idx), None | Some('\n'));
+        let mut inserted = format!("{}{piece}", " ".repeat(column.saturating_sub(line_column)));
+        if at_line_end {
+            inserted.truncate(inserted.trim_end_matches(' ').len());
+        }
+        sw.insert(idx, &inserted);
+    }
+    sw.end_change();
+
+    sw.cursor_char_index = char_at_column(sw, first_line, column).0;
+}
+
+/// Returns the first char of `line_index` that starts at or after the visual column `column`,
+/// or the end of the line if it's shorter, along with the column that char starts at.
+fn char_at_column(sw: &Window, line_index: usize, column: usize) -> (usize, usize) {
+    let tab_width = sw.tab_width();
+    let mut idx = sw.text().line_to_char(line_index);
+    let mut line_column = 0;
+    for c in sw.text().line(line_index).chars() {
+        if c == '\n' || line_column >= column {
+            break;
+        }
+        line_column += char_display_width(c, line_column, tab_width);
+        idx += 1;
+    }
+    (idx, line_column)
+}
+
+/// Returns whether there was a change to undo.
+pub fn undo(app: &mut App) -> bool {
+    let Some(sw) = app.selected_window_mut() else {
+        app.log.log("Error: No open windows");
+        return false;
+    };
+    let undone = sw.undo();
+    if !undone {
+        app.log.log("Already at oldest change");
+    }
+    undone
+}
+
+/// Returns whether there was a change to redo.
+pub fn redo(app: &mut App) -> bool {
+    let Some(sw) = app.selected_window_mut() else {
+        app.log.log("Error: No open windows");
+        return false;
+    };
+    let redone = sw.redo();
+    if !redone {
+        app.log.log("Already at newest change");
+    }
+    redone
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::keys::test_util::{app_with_text, feed_keys, text};
+
+    fn modified(app: &crate::frontend::app::App) -> bool {
+        app.edit_windows[app.selected_window].buffer().modified
+    }
+
+    #[test]
+    fn undo_and_redo_one_change_at_a_time() {
+        let mut app = app_with_text("hello\n");
+        feed_keys(&mut app, "xx");
+        assert_eq!(text(&app), "llo\n");
+        feed_keys(&mut app, "u");
+        assert_eq!(text(&app), "ello\n");
+        feed_keys(&mut app, "u");
+        assert_eq!(text(&app), "hello\n");
+        feed_keys(&mut app, "<C-r>");
+        assert_eq!(text(&app), "ello\n");
+    }
+
+    #[test]
+    fn counted_undo_stops_at_the_oldest_change() {
+        let mut app = app_with_text("hello\n");
+        feed_keys(&mut app, "xxx");
+        feed_keys(&mut app, "99999999999u");
+        assert_eq!(text(&app), "hello\n");
+        feed_keys(&mut app, "99999999999<C-r>");
+        assert_eq!(text(&app), "lo\n");
+    }
+
+    #[test]
+    fn insert_is_undone_as_one_change() {
+        let mut app = app_with_text("\n");
+        feed_keys(&mut app, "ione two<Esc>othree<Esc>");
+        assert_eq!(text(&app), "one two\nthree\n");
+        feed_keys(&mut app, "u");
+        assert_eq!(text(&app), "one two\n");
+    }
+
+    #[test]
+    fn undoing_back_to_the_written_text_is_not_a_modification() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("file.txt");
+        let mut app = app_with_text("hello\n");
+        app.edit_windows[app.selected_window]
+            .buffer_mut()
+            .attached_file_path = Some(path.to_str().unwrap().to_string());
+        feed_keys(&mut app, "x");
+        assert!(modified(&app));
+        feed_keys(&mut app, ":w<CR>");
+        assert!(!modified(&app));
+        feed_keys(&mut app, "x");
+        assert!(modified(&app));
+        feed_keys(&mut app, "u");
+        assert!(!modified(&app));
+        feed_keys(&mut app, "u");
+        assert!(modified(&app));
+        feed_keys(&mut app, "<C-r>");
+        assert!(!modified(&app));
+        feed_keys(&mut app, "<C-r>");
+        assert!(modified(&app));
+    }
+
+    #[test]
+    fn blockwise_yanks_are_put_as_blocks() {
+        let mut app = app_with_text("abcd\nefgh\nijkl\n");
+        feed_keys(&mut app, "l<C-v>jly");
+        feed_keys(&mut app, "gg$p");
+        assert_eq!(text(&app), "abcdbc\nefghfg\nijkl\n");
+        feed_keys(&mut app, "u");
+        assert_eq!(text(&app), "abcd\nefgh\nijkl\n");
+        feed_keys(&mut app, "ggP");
+        assert_eq!(text(&app), "bcabcd\nfgefgh\nijkl\n");
+    }
+
+    #[test]
+    fn blocks_are_padded_and_add_lines_past_the_end() {
+        let mut app = app_with_text("abc\nx\nlong line\n");
+        feed_keys(&mut app, "<C-v>jj$y");
+        assert_eq!(text(&app), "abc\nx\nlong line\n");
+        feed_keys(&mut app, "G0lP");
+        assert_eq!(text(&app), "abc\nx\nlabc      ong line\n x\n long line\n");
+    }
+}
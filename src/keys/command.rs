@@ -1,250 +1,582 @@
-use std::{
-    fs::File,
-    io::{self, BufRead, BufReader, BufWriter},
-};
-
-use std::io::Write;
+use std::{fs, io::Write, time::Instant};
 
-use crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
+use crossterm::event::{KeyEvent, KeyEventKind};
 use ropey::Rope;
 use tempfile::Builder;
 
-use crate::frontend::{
-    app::{App, Mode},
-    dialog::Dialog,
-    window::Window,
+use crate::{
+    file,
+    frontend::{
+        app::{App, Mode},
+        dialog::Dialog,
+        grep::Grep,
+        language::Language,
+        layout::SplitDirection,
+        options::{Assignment, OptionInfo, OptionValue, OPTIONS},
+        search,
+        theme::theme_names,
+    },
+    keys::keymap::{Binding, KeyChord, MapMode, Resolved},
 };
 
-fn do_write(window: &Window, path: &str) -> io::Result<()> {
-    window.text.write_to(BufWriter::new(File::create(path)?))?;
-    Ok(())
+pub const DEFAULT_BINDINGS: &[(&str, Binding)] = &[
+    ("<Esc>", Binding::Line(LineAction::Cancel)),
+    ("<CR>", Binding::Line(LineAction::Confirm)),
+    ("<Left>", Binding::Line(LineAction::Left)),
+    ("<Right>", Binding::Line(LineAction::Right)),
+    ("<BS>", Binding::Line(LineAction::DeleteBackward)),
+    ("<Down>", Binding::Line(LineAction::SelectNext)),
+    ("<C-n>", Binding::Line(LineAction::SelectNext)),
+    ("<Up>", Binding::Line(LineAction::SelectPrevious)),
+    ("<C-p>", Binding::Line(LineAction::SelectPrevious)),
+];
+
+/// What keys other than plain characters do in a line being typed. Selecting only does
+/// something in the file finder and the window picker.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum LineAction {
+    Cancel,
+    Confirm,
+    Left,
+    Right,
+    DeleteBackward,
+    SelectNext,
+    SelectPrevious,
 }
 
-pub fn process_keys_dialog(event: KeyEvent, app: &mut App) -> bool {
+/// A key typed into a line, either a character or what it is bound to.
+#[derive(Copy, Clone)]
+pub enum LineKey {
+    Char(char),
+    Action(LineAction),
+}
+
+/// Takes the next key typed into a line off the pending keys. Returns `None` once there are
+/// none left or they may still start a longer binding, unless `force` is set.
+pub fn next_line_key(app: &mut App, force: bool) -> Option<LineKey> {
+    while !app.pending_keys.is_empty() {
+        match app.keymaps.command.resolve(&mut app.pending_keys, force) {
+            Resolved::Binding(Binding::Line(action)) => return Some(LineKey::Action(action)),
+            Resolved::Char(c) => return Some(LineKey::Char(c)),
+            Resolved::Pending => return None,
+            Resolved::Binding(_) | Resolved::Ignored => {}
+        }
+    }
+    None
+}
+
+/// Adds a key pressed while typing a line to the pending keys.
+pub fn push_line_key(app: &mut App, event: KeyEvent) -> bool {
     if let KeyEventKind::Press = event.kind {
-        #[allow(clippy::single_match)]
-        match event.code {
-            KeyCode::Esc => app.current_mode = Mode::Normal,
-            KeyCode::Enter => {
-                let old_mode = std::mem::replace(&mut app.current_mode, Mode::Normal);
-                if let Mode::Command { buffer, .. } = old_mode {
-                    if buffer.is_empty() {
-                        app.log.log("Empty buffer, aborting");
-                        return false;
-                    }
+        app.pending_keys.push(KeyChord::from_event(event));
+        app.pending_keys_since = Some(Instant::now());
+        return true;
+    }
+    false
+}
 
-                    let args = buffer.split_whitespace().collect::<Vec<&str>>();
-                    if args.is_empty() {
-                        app.log.log("Empty buffer, aborting");
-                        return false;
-                    }
+/// Forgets the pending keys once they are used up. Keys left over after leaving the mode they
+/// were typed in are dropped.
+pub fn finish_line_keys(app: &mut App, mode_left: bool) {
+    if mode_left {
+        app.pending_keys.clear();
+    }
+    if app.pending_keys.is_empty() {
+        app.pending_keys_since = None;
+    }
+}
 
-                    match args.as_slice() {
-                        ["q!" | "quit!"] => return true,
-                        ["q" | "quit"] => {
-                            if app.has_modified_windows() {
-                                app.log.log(
-                                    "There are unsaved changes! Use q! or quit! to force quit.",
-                                );
-                            } else {
-                                return true;
-                            }
-                        }
-                        ["log" | "logs"] => {
-                            app.current_mode = Mode::Dialog {
-                                which_one: Dialog::Logs,
-                            }
-                        }
-                        ["c" | "close"] => {
-                            if app.selected_window().is_some() {
-                                let m = app.selected_window().unwrap().modified;
-                                if m {
-                                    app.log.log("There are unsaved changes!".to_string());
-                                } else {
-                                    let closed = app.close_selected();
-                                    app.log.log(format!("Closed {}", closed.resolve_title()));
-                                }
-                            } else {
-                                app.log.log("No window Selected");
-                            }
-                        }
-                        ["a" | "attach", param] => {
-                            if let Some(sw) = app.selected_window_mut() {
-                                sw.attached_file_path = Some(param.to_string());
-                                sw.try_detect_langauge();
-                                app.log
-                                    .log(format!("Attached the current window to {param}"));
-                            } else {
-                                app.log.log("No window selected");
-                            }
-                            app.queue_selected_window_highlight_refresh();
-                        }
-                        ["n" | "new"] => app.selected_window = app.create_empty_window(),
-                        ["w" | "write"] => {
-                            let to_log: String;
-                            if let Some(sw) = app.selected_window_mut() {
-                                if let Some(path) = &sw.attached_file_path {
-                                    match do_write(sw, path.as_str()) {
-                                        Ok(_) => {
-                                            sw.modified = false;
-                                            to_log = format!(
-                                                "Successfully wrote {} bytes to {}",
-                                                sw.text.len_bytes(),
-                                                path
-                                            );
-                                        }
-                                        Err(e) => {
-                                            to_log = format!(
-                                                "Error: Could not write {} to {}: {:?}",
-                                                sw.resolve_title(),
-                                                path,
-                                                e
-                                            );
-                                        }
-                                    }
-                                } else {
-                                    to_log = "This window is not attached".to_string();
-                                }
-                            } else {
-                                to_log = "Error: No open window".to_string();
-                            }
-
-                            app.log.log(to_log);
-                        }
-                        ["o" | "open", path] => match File::open(path) {
-                            Ok(f) => {
-                                let mut reader = BufReader::new(f);
-                                let mut text = String::new();
-                                let mut line_buf = String::new();
-                                let cancel = loop {
-                                    line_buf.clear();
-                                    let res = reader.read_line(&mut line_buf);
-                                    match res {
-                                        Ok(0) => break false,
-                                        Ok(_) => {
-                                            let replaced = line_buf.replace('\t', "    ");
-                                            text.push_str(replaced.as_str());
-                                        }
-                                        Err(e) => {
-                                            app.log.log(format!("Could not open {path}: {:?}", e));
-                                            break true;
-                                        }
-                                    }
-                                };
-                                if !cancel {
-                                    let rope = Rope::from_str(text.as_str());
-
-                                    let window_index = app.create_empty_window();
-                                    let window = &mut app.edit_windows[window_index];
-                                    window.text = rope;
-                                    window.attached_file_path = Some(path.to_string());
-                                    if let Some(lang) = window.try_detect_langauge() {
-                                        app.log.log(format!("Detected {}", lang.display_name()));
-                                    } else {
-                                        app.log.log("Couldn't detect language");
-                                    }
-                                    app.selected_window = app.edit_windows.len() - 1;
-                                    app.log.log(format!("Successfully opened {path}"));
-                                    app.queue_selected_window_highlight_refresh();
-                                }
-                            }
-                            Err(e) => app.log.log(format!("Could not open {path}: {:?}", e)),
-                        },
-                        ["format" | "fmt"] => {
-                            if let Some(sw) = app.selected_window_mut() {
-                                if let Some(lang) = sw.language {
-                                    match Builder::new()
-                                        .prefix("ted_format_")
-                                        .suffix(".go")
-                                        .tempfile()
-                                    {
-                                        Ok(mut f) => match write!(f, "{}", sw.text) {
-                                            Ok(_) => {
-                                                if let Some(mut cmd) =
-                                                    lang.format_command(f.path().to_str().unwrap())
-                                                {
-                                                    let output = cmd
-                                                        .output()
-                                                        .expect("Failed to run command");
-                                                    let mut new_text =
-                                                        std::fs::read_to_string(f).unwrap();
-                                                    new_text = new_text.replace('\t', "    ");
-                                                    sw.text = Rope::from_str(new_text.as_str());
-                                                    if sw.cursor_char_index >= sw.text.len_chars() {
-                                                        sw.cursor_char_index =
-                                                            sw.text.len_chars().max(1) - 1;
-                                                    }
-                                                    app.log.log(format!(
-                                                        "Successfully formatted! Exit code: {}",
-                                                        output.status.code().unwrap_or(0)
-                                                    ));
-                                                    app.queue_selected_window_highlight_refresh();
-                                                }
-                                            }
-                                            Err(e) => {
-                                                app.log.log(format!("Could not format: {:?}", e))
-                                            }
-                                        },
-                                        Err(e) => app.log.log(format!("Could not format: {:?}", e)),
-                                    }
-                                }
-                            }
-                        }
-                        ["settitle", new_title] => {
-                            if let Some(sw) = app.selected_window_mut() {
-                                sw.ident = Some(new_title.to_string());
-                                app.log
-                                    .log(format!("Successfully set title to {}", new_title));
-                            } else {
-                                app.log.log("No window selected");
-                            }
-                        }
-                        _ => app
-                            .log
-                            .log(format!("Could not find interpretation for {buffer}")),
+fn write_selected(app: &mut App, force: bool) {
+    let to_log: String;
+    if let Some(sw) = app.selected_window_mut() {
+        let mut guard = sw.buffer_mut();
+        let buffer = &mut *guard;
+        if let Some(path) = &buffer.attached_file_path {
+            match file::changed_on_disk(path, buffer.disk_state) {
+                Ok(true) if !force => {
+                    to_log = format!(
+                        "Error: {path} changed on disk since it was opened, use w! to overwrite it"
+                    );
+                }
+                Err(e) if !force => {
+                    to_log = format!(
+                        "Error: Could not check {path} for changes on disk ({e}), use w! to write anyway"
+                    );
+                }
+                Ok(_) | Err(_) => match file::save(path, &buffer.text, buffer.file_format) {
+                    Ok((bytes_written, disk_state)) => {
+                        buffer.history.mark_saved();
+                        buffer.modified = false;
+                        buffer.disk_state = Some(disk_state);
+                        to_log = format!(
+                            "Successfully wrote {} bytes to {} [{}]",
+                            bytes_written, path, buffer.file_format
+                        );
+                    }
+                    Err(e) => {
+                        to_log = format!(
+                            "Error: Could not write {} to {}: {:?}",
+                            buffer.resolve_title(),
+                            path,
+                            e
+                        );
                     }
+                },
+            }
+        } else {
+            to_log = "This window is not attached".to_string();
+        }
+    } else {
+        to_log = "Error: No open window".to_string();
+    }
+
+    app.log.log(to_log);
+}
+
+/// Runs a line typed in command mode. Returns whether ted should quit.
+pub fn run_command(app: &mut App, buffer: &str) -> bool {
+    if buffer.is_empty() {
+        app.log.log("Empty buffer, aborting");
+        return false;
+    }
+    if super::ex::execute(app, buffer) {
+        return false;
+    }
+
+    let args = buffer.split_whitespace().collect::<Vec<&str>>();
+    if args.is_empty() {
+        app.log.log("Empty buffer, aborting");
+        return false;
+    }
+
+    match args.as_slice() {
+        ["q!" | "quit!"] => return true,
+        ["q" | "quit"] if app.tab().panes.pane_count() > 1 => {
+            app.close_pane();
+        }
+        ["q" | "quit"] if app.tabs.len() > 1 => {
+            app.close_tab();
+        }
+        ["q" | "quit"] => {
+            if app.has_modified_windows() {
+                app.log
+                    .log("There are unsaved changes! Use q! or quit! to force quit.");
+            } else {
+                return true;
+            }
+        }
+        ["log" | "logs"] => {
+            app.current_mode = Mode::Dialog {
+                which_one: Dialog::Logs,
+            }
+        }
+        ["reg" | "registers"] => {
+            app.current_mode = Mode::Dialog {
+                which_one: Dialog::Registers,
+            }
+        }
+        ["c" | "close"] => {
+            if app.selected_window().is_some() {
+                let m = app.selected_window().unwrap().closing_loses_changes();
+                if m {
+                    app.log.log("There are unsaved changes!".to_string());
                 } else {
-                    app.log.log("Error: Not in command mode");
+                    let closed = app.close_selected();
+                    app.log.log(format!("Closed {}", closed.resolve_title()));
                 }
+            } else {
+                app.log.log("No window Selected");
             }
-            KeyCode::Left => {
-                if let Mode::Command { char_idx, .. } = &mut app.current_mode {
-                    if *char_idx > 0 {
-                        *char_idx -= 1;
-                    }
-                } else {
-                    app.log.log("Error: Not in command mode");
+        }
+        ["a" | "attach", param] => {
+            let path = app.tab().resolve_path(param);
+            if let Some(sw) = app.selected_window_mut() {
+                let mut buffer = sw.buffer_mut();
+                buffer.attached_file_path = Some(path);
+                buffer.disk_state = None;
+                drop(buffer);
+                app.detect_language(app.selected_window);
+                app.log
+                    .log(format!("Attached the current window to {param}"));
+            } else {
+                app.log.log("No window selected");
+            }
+        }
+        ["grep"] => match app.grep {
+            Some(_) => {
+                app.current_mode = Mode::Dialog {
+                    which_one: Dialog::SearchResults,
                 }
             }
-            KeyCode::Right => {
-                if let Mode::Command { buffer, char_idx } = &mut app.current_mode {
-                    if *char_idx + 1 < buffer.len() {
-                        *char_idx += 1;
-                    }
-                } else {
-                    app.log.log("Error: Not in command mode");
+            None => app.log.log("Error: No previous grep"),
+        },
+        ["grep", pattern] => grep(app, pattern, &app.tab().directory()),
+        ["grep", pattern, path] => grep(app, pattern, &app.tab().resolve_path(path)),
+        ["sp" | "split"] => app.split_pane(SplitDirection::Horizontal),
+        ["vs" | "vsplit"] => app.split_pane(SplitDirection::Vertical),
+        ["sp" | "split", path] => split_open(app, SplitDirection::Horizontal, path),
+        ["vs" | "vsplit", path] => split_open(app, SplitDirection::Vertical, path),
+        ["on" | "only"] => app.only_pane(),
+        ["find" | "files"] => super::dialog::open_file_finder(app),
+        ["noh" | "nohlsearch"] => app.highlight_search = false,
+        ["u" | "undo"] => {
+            super::action::undo(app);
+        }
+        ["red" | "redo"] => {
+            super::action::redo(app);
+        }
+        ["n" | "new"] => app.selected_window = app.create_empty_window(),
+        ["w" | "write"] => write_selected(app, false),
+        ["w!" | "write!"] => write_selected(app, true),
+        ["o" | "open", path] => {
+            open(app, path);
+        }
+        ["tabnew" | "tabe" | "tabedit"] => app.new_tab(),
+        ["tabnew" | "tabe" | "tabedit", path] => {
+            app.new_tab();
+            let empty = app.selected_window().map(|sw| sw.uuid);
+            if open(app, path) {
+                // The file takes the place of the empty window the tab started with
+                if let Some(index) = app.edit_windows.iter().position(|w| Some(w.uuid) == empty) {
+                    app.close_window(index);
                 }
+                app.sync_panes();
             }
-            KeyCode::Backspace => {
-                if let Mode::Command { buffer, char_idx } = &mut app.current_mode {
-                    if *char_idx > 0 {
-                        buffer.remove(*char_idx - 1);
-                        *char_idx -= 1;
-                    }
-                } else {
-                    app.log.log("Error: Not in command mode");
+        }
+        ["tabc" | "tabclose"] => {
+            if !app.close_tab() {
+                app.log.log("Error: Cannot close the last tab");
+            }
+        }
+        ["tabn" | "tabnext"] => app.next_tab(),
+        ["tabp" | "tabprevious"] => app.previous_tab(1),
+        ["tcd", path] => change_tab_directory(app, Some(path)),
+        ["tcd"] => change_tab_directory(app, None),
+        ["pwd"] => {
+            let directory = match &app.tab().cwd {
+                Some(cwd) => Ok(cwd.clone()),
+                None => std::env::current_dir(),
+            };
+            match directory {
+                Ok(directory) => app.log.log(directory.display().to_string()),
+                Err(e) => app.log.log(format!("Error: {:?}", e)),
+            }
+        }
+        ["format" | "fmt"] => format_selected(app),
+        // Shorthands for :setlocal from before there were options
+        [option @ ("et" | "expandtab" | "noet" | "noexpandtab")] => {
+            set_options(app, &[option], true)
+        }
+        ["ts" | "tabstop", width] => set_options(app, &[&format!("tabstop={width}")], true),
+        ["set" | "se"] => {
+            let values: Vec<String> = OPTIONS
+                .iter()
+                .map(|option| format!("{}={}", option.name, option_value(app, option)))
+                .collect();
+            app.log.log(values.join(" "));
+        }
+        ["set" | "se", args @ ..] => set_options(app, args, false),
+        ["setlocal" | "setl", args @ ..] => set_options(app, args, true),
+        ["colo" | "colorscheme"] => app.log.log(format!(
+            "Theme {}, available: {}",
+            app.theme.name,
+            theme_names().join(", ")
+        )),
+        ["colo" | "colorscheme", name] => {
+            match app.set_option("theme", OptionValue::String(name.to_string())) {
+                Ok(()) => app.log.log(format!("Switched to theme {name}")),
+                Err(e) => app.log.log(format!("Error: {e}")),
+            }
+        }
+        ["map" | "maps"] => super::dialog::open_keymaps(app),
+        [command @ ("map" | "nmap" | "vmap" | "imap" | "dmap" | "cmap"), keys, name] => {
+            for mode in map_modes(command) {
+                match app.keymaps.map(*mode, keys, name) {
+                    Ok(()) => app
+                        .log
+                        .log(format!("Mapped {keys} to {name} in {} mode", mode.name())),
+                    Err(e) => app.log.log(format!("Error: {e}")),
                 }
             }
-            KeyCode::Char(c) => {
-                if let Mode::Command { buffer, char_idx } = &mut app.current_mode {
-                    buffer.insert(*char_idx, c);
-                    *char_idx += 1;
-                } else {
-                    app.log.log("Error: Not in command mode");
+        }
+        [command @ ("unmap" | "nunmap" | "vunmap" | "iunmap" | "dunmap" | "cunmap"), keys] => {
+            for mode in map_modes(command) {
+                match app.keymaps.unmap(*mode, keys) {
+                    Ok(()) => app
+                        .log
+                        .log(format!("Unmapped {keys} in {} mode", mode.name())),
+                    Err(e) => app.log.log(format!("Error: {e}")),
                 }
             }
-            _ => {}
         }
+        ["settitle", new_title] => {
+            if let Some(sw) = app.selected_window_mut() {
+                sw.buffer_mut().ident = Some(new_title.to_string());
+                app.log
+                    .log(format!("Successfully set title to {}", new_title));
+            } else {
+                app.log.log("No window selected");
+            }
+        }
+        _ => app
+            .log
+            .log(format!("Could not find interpretation for {buffer}")),
     }
     false
 }
+
+pub fn process_keys_command(event: KeyEvent, app: &mut App) -> bool {
+    if push_line_key(app, event) {
+        return resolve_pending_keys(app, false);
+    }
+    false
+}
+
+/// Edits the command line with the pending keys and runs it on Enter. Returns whether the
+/// command quits ted.
+pub fn resolve_pending_keys(app: &mut App, force: bool) -> bool {
+    while let Some(key) = next_line_key(app, force) {
+        let Mode::Command { buffer, char_idx } = &mut app.current_mode else {
+            break;
+        };
+        match key {
+            LineKey::Action(LineAction::Cancel) => app.current_mode = Mode::Normal,
+            LineKey::Action(LineAction::Confirm) => {
+                let buffer = std::mem::take(buffer);
+                app.current_mode = Mode::Normal;
+                app.pending_keys.clear();
+                app.pending_keys_since = None;
+                return run_command(app, &buffer);
+            }
+            key => {
+                edit_command_line(buffer, char_idx, key);
+            }
+        }
+    }
+    let mode_left = !matches!(app.current_mode, Mode::Command { .. });
+    finish_line_keys(app, mode_left);
+    false
+}
+
+/// Applies the arguments of `:set` or `:setlocal` one after the other.
+fn set_options(app: &mut App, args: &[&str], local: bool) {
+    for arg in args {
+        if let Err(e) = set_option(app, arg, local) {
+            app.log.log(format!("Error: {e}"));
+        }
+    }
+}
+
+/// Applies one argument of `:set` or `:setlocal`. `:set` changes both the global value and
+/// the one of the current buffer, `:setlocal` only the latter.
+fn set_option(app: &mut App, arg: &str, local: bool) -> Result<(), String> {
+    let assignment = Assignment::parse(arg)?;
+    let (Assignment::Set(option, _) | Assignment::Toggle(option) | Assignment::Show(option)) =
+        &assignment;
+    let option = *option;
+    if local && !option.local {
+        return Err(format!("{} is a global option", option.name));
+    }
+    let value = match assignment {
+        Assignment::Show(_) => {
+            let value = option_value(app, option);
+            app.log.log(format!("{}={value}", option.name));
+            return Ok(());
+        }
+        Assignment::Toggle(_) => {
+            OptionValue::Bool(option_value(app, option) != OptionValue::Bool(true))
+        }
+        Assignment::Set(_, value) => value,
+    };
+    if option.local {
+        match app.selected_window() {
+            Some(sw) => sw.buffer_mut().options.set(option.name, value.clone())?,
+            None if local => return Err("No window selected".to_string()),
+            None => {}
+        }
+    }
+    if !local {
+        app.set_option(option.name, value.clone())?;
+    }
+    app.log.log(format!("Set {} to {value}", option.name));
+    Ok(())
+}
+
+/// Returns the value of `option` for the current buffer.
+fn option_value(app: &App, option: &OptionInfo) -> OptionValue {
+    let local_value = app
+        .selected_window()
+        .filter(|_| option.local)
+        .and_then(|sw| sw.buffer().options.get(option.name));
+    local_value
+        .or_else(|| app.options.get(option.name))
+        .expect("Every option has a value")
+}
+
+/// Returns the modes a `:map` or `:unmap` command applies to, plain `:map` covers normal and
+/// visual mode like in vim.
+fn map_modes(command: &str) -> &'static [MapMode] {
+    match command.trim_end_matches("map").trim_end_matches("un") {
+        "n" => &[MapMode::Normal],
+        "v" => &[MapMode::Visual],
+        "i" => &[MapMode::Insert],
+        "d" => &[MapMode::Dialog],
+        "c" => &[MapMode::Command],
+        _ => &[MapMode::Normal, MapMode::Visual],
+    }
+}
+
+/// Opens `path` in a new window and returns whether that worked.
+fn open(app: &mut App, path: &str) -> bool {
+    let path = app.tab().resolve_path(path);
+    match app.open_file(&path) {
+        Ok(window_index) => {
+            let file_format = app.edit_windows[window_index].buffer().file_format;
+            if let Some(lang) = app.detect_language(window_index) {
+                app.log.log(format!("Detected {}", lang.display_name()));
+            } else {
+                app.log.log("Couldn't detect language");
+            }
+            app.log
+                .log(format!("Successfully opened {path} [{file_format}]"));
+            true
+        }
+        Err(e) => {
+            app.log.log(format!("Could not open {path}: {:?}", e));
+            false
+        }
+    }
+}
+
+/// Replaces the text of the current buffer with the output of its language's formatter.
+fn format_selected(app: &mut App) {
+    let Some(sw) = app.selected_window_mut() else {
+        return;
+    };
+    let Some(lang) = sw.buffer().language else {
+        return;
+    };
+    match format_text(lang, &sw.text()) {
+        Ok(new_text) => {
+            sw.replace_text(new_text.as_str());
+            if sw.cursor_char_index >= sw.text().len_chars() {
+                sw.cursor_char_index = sw.text().len_chars().max(1) - 1;
+            }
+            app.log.log("Successfully formatted!");
+        }
+        Err(e) => app.log.log(format!("Error: Could not format: {e}")),
+    }
+}
+
+/// Runs the formatter of `lang` on `text` and returns the formatted text, or what went wrong
+/// if the formatter is missing or failed.
+fn format_text(lang: Language, text: &Rope) -> Result<String, String> {
+    // Formatters may look at the extension to tell what they are formatting
+    let suffix = lang
+        .file_extension()
+        .map_or(String::new(), |extension| format!(".{extension}"));
+    let mut f = Builder::new()
+        .prefix("ted_format_")
+        .suffix(&suffix)
+        .tempfile()
+        .map_err(|e| e.to_string())?;
+    write!(f, "{text}").map_err(|e| e.to_string())?;
+    let mut cmd = lang
+        .format_command(&f.path().to_string_lossy())
+        .ok_or_else(|| format!("no formatter for {}", lang.display_name()))?;
+    let program = cmd.get_program().to_string_lossy().into_owned();
+    let output = cmd
+        .output()
+        .map_err(|e| format!("could not run {program}: {e}"))?;
+    if !output.status.success() {
+        return Err(format!(
+            "{program} exited with {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    fs::read_to_string(f.path()).map_err(|e| e.to_string())
+}
+
+/// Sets the working directory of the current tab, or goes back to the one ted was started in.
+fn change_tab_directory(app: &mut App, path: Option<&str>) {
+    let Some(path) = path else {
+        app.tab_mut().cwd = None;
+        return;
+    };
+    match fs::canonicalize(app.tab().resolve_path(path)) {
+        Ok(directory) if directory.is_dir() => {
+            app.log
+                .log(format!("Working directory is {}", directory.display()));
+            app.tab_mut().cwd = Some(directory);
+        }
+        Ok(_) => app.log.log(format!("Error: {path} is not a directory")),
+        Err(e) => app
+            .log
+            .log(format!("Error: Cannot change to {path}: {:?}", e)),
+    }
+}
+
+/// Splits the focused pane and opens `path` in the new half.
+fn split_open(app: &mut App, direction: SplitDirection, path: &str) {
+    if app.selected_window().is_some() {
+        app.split_pane(direction);
+    }
+    let path = app.tab().resolve_path(path);
+    if let Err(e) = app.select_or_open_file(&path) {
+        app.log.log(format!("Could not open {path}: {:?}", e));
+    }
+}
+
+/// Searches the files below `path` for `pattern` and shows the results as they come in.
+fn grep(app: &mut App, pattern: &str, path: &str) {
+    match search::build_regex(pattern) {
+        Ok(regex) => {
+            app.grep = Some(Grep::start(pattern, regex, path));
+            app.current_mode = Mode::Dialog {
+                which_one: Dialog::SearchResults,
+            };
+        }
+        Err(e) => app
+            .log
+            .log(format!("Error: Invalid pattern {pattern}: {e}")),
+    }
+}
+
+/// Edits the line typed in command or search mode, `char_idx` being the cursor position in
+/// chars. Returns whether the key changed the text of the line.
+pub fn edit_command_line(buffer: &mut String, char_idx: &mut usize, key: LineKey) -> bool {
+    let byte_index = |buffer: &str, char_idx: usize| {
+        buffer
+            .char_indices()
+            .nth(char_idx)
+            .map(|(i, _)| i)
+            .unwrap_or(buffer.len())
+    };
+    match key {
+        LineKey::Action(LineAction::Left) => {
+            *char_idx = char_idx.saturating_sub(1);
+            false
+        }
+        LineKey::Action(LineAction::Right) => {
+            if *char_idx < buffer.chars().count() {
+                *char_idx += 1;
+            }
+            false
+        }
+        LineKey::Action(LineAction::DeleteBackward) => {
+            if *char_idx == 0 {
+                return false;
+            }
+            *char_idx -= 1;
+            buffer.remove(byte_index(buffer, *char_idx));
+            true
+        }
+        LineKey::Char(c) => {
+            buffer.insert(byte_index(buffer, *char_idx), c);
+            *char_idx += 1;
+            true
+        }
+        LineKey::Action(_) => false,
+    }
+}
@@ -1,39 +1,276 @@
-use crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
+use crossterm::event::KeyEvent;
 
 use crate::frontend::{
     app::{App, Mode},
     dialog::Dialog,
+    finder::FileFinder,
+    picker::WindowPicker,
 };
 
+use super::{
+    command::{
+        edit_command_line, finish_line_keys, next_line_key, push_line_key, LineAction, LineKey,
+    },
+    keymap::{Binding, MapMode, Resolved},
+};
+
+pub const DEFAULT_BINDINGS: &[(&str, Binding)] = &[
+    ("<Esc>", Binding::Dialog(DialogAction::Cancel)),
+    ("q", Binding::Dialog(DialogAction::Cancel)),
+    ("<CR>", Binding::Dialog(DialogAction::Confirm)),
+    ("j", Binding::Dialog(DialogAction::SelectNext)),
+    ("l", Binding::Dialog(DialogAction::SelectNext)),
+    ("L", Binding::Dialog(DialogAction::SelectNext)),
+    ("<Down>", Binding::Dialog(DialogAction::SelectNext)),
+    ("<Right>", Binding::Dialog(DialogAction::SelectNext)),
+    ("<C-n>", Binding::Dialog(DialogAction::SelectNext)),
+    ("k", Binding::Dialog(DialogAction::SelectPrevious)),
+    ("h", Binding::Dialog(DialogAction::SelectPrevious)),
+    ("H", Binding::Dialog(DialogAction::SelectPrevious)),
+    ("<Up>", Binding::Dialog(DialogAction::SelectPrevious)),
+    ("<Left>", Binding::Dialog(DialogAction::SelectPrevious)),
+    ("<C-p>", Binding::Dialog(DialogAction::SelectPrevious)),
+    ("/", Binding::Dialog(DialogAction::Filter)),
+    ("d", Binding::Dialog(DialogAction::CloseWindow)),
+    ("D", Binding::Dialog(DialogAction::ForceCloseWindow)),
+];
+
+/// What keys do in dialogs other than the file finder. Filtering and closing windows only
+/// work in the window picker.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum DialogAction {
+    Cancel,
+    Confirm,
+    SelectNext,
+    SelectPrevious,
+    Filter,
+    CloseWindow,
+    ForceCloseWindow,
+}
+
+pub fn open_file_finder(app: &mut App) {
+    app.current_mode = Mode::Dialog {
+        which_one: Dialog::FileFinder(FileFinder::new(&app.tab().directory())),
+    };
+}
+
+pub fn open_window_picker(app: &mut App) {
+    app.mark_selected_window_used();
+    app.current_mode = Mode::Dialog {
+        which_one: Dialog::Windows(WindowPicker::new(&app.edit_windows)),
+    };
+}
+
+pub fn open_keymaps(app: &mut App) {
+    app.current_mode = Mode::Dialog {
+        which_one: Dialog::Keymaps { scroll: 0 },
+    };
+}
+
 pub fn process_keys_dialog(event: KeyEvent, app: &mut App) -> bool {
-    if let KeyEventKind::Press = event.kind {
-        if let Mode::Dialog { which_one } = &app.current_mode {
-            #[allow(clippy::single_match)]
-            match event.code {
-                KeyCode::Esc | KeyCode::Enter => app.current_mode = Mode::Normal,
-                KeyCode::Left
-                | KeyCode::Char('h')
-                | KeyCode::Char('H')
-                | KeyCode::Char('k')
-                | KeyCode::Up => {
-                    if let Dialog::Windows { .. } = which_one {
-                        app.previous_window();
-                    }
-                }
-                KeyCode::Right
-                | KeyCode::Char('l')
-                | KeyCode::Char('L')
-                | KeyCode::Char('j')
-                | KeyCode::Down => {
-                    if let Dialog::Windows { .. } = which_one {
-                        app.next_window();
-                    }
+    if push_line_key(app, event) {
+        resolve_pending_keys(app, false);
+    }
+    false
+}
+
+/// Runs the bindings the pending keys form. The file finder and the filter of the window
+/// picker use the keymap of typed lines, the other dialogs their own.
+pub fn resolve_pending_keys(app: &mut App, force: bool) {
+    while let Mode::Dialog { which_one } = &app.current_mode {
+        let typing = match which_one {
+            Dialog::FileFinder(_) => true,
+            Dialog::Windows(picker) => picker.filtering,
+            _ => false,
+        };
+        if typing {
+            let Some(key) = next_line_key(app, force) else {
+                break;
+            };
+            match &app.current_mode {
+                Mode::Dialog {
+                    which_one: Dialog::FileFinder(_),
+                } => file_finder_key(app, key),
+                _ => window_filter_key(app, key),
+            }
+            continue;
+        }
+        if app.pending_keys.is_empty() {
+            break;
+        }
+        match app.keymaps.dialog.resolve(&mut app.pending_keys, force) {
+            Resolved::Binding(Binding::Dialog(action)) => run_dialog_action(app, action),
+            Resolved::Pending => break,
+            _ => {}
+        }
+    }
+    let mode_left = !matches!(app.current_mode, Mode::Dialog { .. });
+    finish_line_keys(app, mode_left);
+}
+
+fn run_dialog_action(app: &mut App, action: DialogAction) {
+    let Mode::Dialog { which_one } = &mut app.current_mode else {
+        return;
+    };
+    match which_one {
+        Dialog::Windows(_) => window_picker_action(app, action),
+        Dialog::Keymaps { scroll } => {
+            let binding_count: usize = MapMode::ALL
+                .into_iter()
+                .map(|mode| app.keymaps.get(mode).bindings().len())
+                .sum();
+            match action {
+                DialogAction::Cancel | DialogAction::Confirm => app.current_mode = Mode::Normal,
+                DialogAction::SelectPrevious => *scroll = scroll.saturating_sub(1),
+                DialogAction::SelectNext => {
+                    *scroll = (*scroll + 1).min(binding_count.saturating_sub(1))
                 }
                 _ => {}
             }
-        } else {
-            app.log.log("Error: Not in dialog mode");
+        }
+        Dialog::SearchResults => match action {
+            DialogAction::Cancel => app.current_mode = Mode::Normal,
+            DialogAction::Confirm => {
+                app.current_mode = Mode::Normal;
+                open_selected_grep_match(app);
+            }
+            DialogAction::SelectPrevious => {
+                if let Some(grep) = &mut app.grep {
+                    grep.select_previous();
+                }
+            }
+            DialogAction::SelectNext => {
+                if let Some(grep) = &mut app.grep {
+                    grep.select_next();
+                }
+            }
+            _ => {}
+        },
+        _ => {
+            if matches!(action, DialogAction::Cancel | DialogAction::Confirm) {
+                app.current_mode = Mode::Normal;
+            }
         }
     }
-    false
+}
+
+/// Opens the file of the selected `:grep` result, or selects its window if it is open, and
+/// puts the cursor on the match.
+fn open_selected_grep_match(app: &mut App) {
+    let Some((path, line_index, column)) = app
+        .grep
+        .as_ref()
+        .and_then(|grep| grep.selected_match())
+        .map(|m| (m.path.clone(), m.line_index, m.column))
+    else {
+        return;
+    };
+    let window_index = match app.select_or_open_file(&path) {
+        Ok(window_index) => window_index,
+        Err(e) => {
+            app.log.log(format!("Could not open {path}: {:?}", e));
+            return;
+        }
+    };
+    let sw = &mut app.edit_windows[window_index];
+    let line_index = line_index.min(sw.text().len_lines() - 1);
+    let line_length = sw.text().line(line_index).len_chars();
+    sw.cursor_char_index = sw.text().line_to_char(line_index) + column.min(line_length);
+    sw.desired_column = None;
+}
+
+/// Typing edits the query of the file finder, selecting picks a file and confirming opens it.
+fn file_finder_key(app: &mut App, key: LineKey) {
+    let Mode::Dialog {
+        which_one: Dialog::FileFinder(finder),
+    } = &mut app.current_mode
+    else {
+        return;
+    };
+    match key {
+        LineKey::Action(LineAction::Cancel) => app.current_mode = Mode::Normal,
+        LineKey::Action(LineAction::Confirm) => {
+            let path = finder.selected_file();
+            app.current_mode = Mode::Normal;
+            if let Some(path) = path {
+                if let Err(e) = app.select_or_open_file(&path) {
+                    app.log.log(format!("Could not open {path}: {:?}", e));
+                }
+            }
+        }
+        LineKey::Action(LineAction::SelectPrevious) => finder.select_previous(),
+        LineKey::Action(LineAction::SelectNext) => finder.select_next(),
+        key => {
+            if edit_command_line(&mut finder.query, &mut finder.char_idx, key) {
+                finder.update_results(false);
+            }
+        }
+    }
+}
+
+/// Typing after `/` in the window picker filters the windows until confirming, or cancelling
+/// which also clears the filter.
+fn window_filter_key(app: &mut App, key: LineKey) {
+    let Mode::Dialog {
+        which_one: Dialog::Windows(picker),
+    } = &mut app.current_mode
+    else {
+        return;
+    };
+    match key {
+        LineKey::Action(LineAction::Cancel) => {
+            picker.filtering = false;
+            picker.query.clear();
+            picker.char_idx = 0;
+            picker.update_entries(&app.edit_windows);
+        }
+        LineKey::Action(LineAction::Confirm) => picker.filtering = false,
+        LineKey::Action(LineAction::SelectPrevious) => picker.select_previous(),
+        LineKey::Action(LineAction::SelectNext) => picker.select_next(),
+        key => {
+            if edit_command_line(&mut picker.query, &mut picker.char_idx, key) {
+                picker.selected = 0;
+                picker.update_entries(&app.edit_windows);
+            }
+        }
+    }
+}
+
+/// Confirming switches to the selected window, closing it refuses if it has unsaved changes
+/// unless forced.
+fn window_picker_action(app: &mut App, action: DialogAction) {
+    let Mode::Dialog {
+        which_one: Dialog::Windows(mut picker),
+    } = std::mem::replace(&mut app.current_mode, Mode::Normal)
+    else {
+        return;
+    };
+    match action {
+        DialogAction::Cancel => return,
+        DialogAction::Confirm => {
+            if let Some(index) = picker.selected_window() {
+                app.selected_window = index;
+            }
+            return;
+        }
+        DialogAction::SelectPrevious => picker.select_previous(),
+        DialogAction::SelectNext => picker.select_next(),
+        DialogAction::Filter => picker.filtering = true,
+        DialogAction::CloseWindow | DialogAction::ForceCloseWindow => {
+            if let Some(index) = picker.selected_window() {
+                let force = action == DialogAction::ForceCloseWindow;
+                if app.edit_windows[index].closing_loses_changes() && !force {
+                    app.log
+                        .log("There are unsaved changes! Use D to close the window anyway.");
+                } else {
+                    let closed = app.close_window(index);
+                    app.log.log(format!("Closed {}", closed.resolve_title()));
+                    picker.update_entries(&app.edit_windows);
+                }
+            }
+        }
+    }
+    app.current_mode = Mode::Dialog {
+        which_one: Dialog::Windows(picker),
+    };
 }
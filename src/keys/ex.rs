@@ -0,0 +1,724 @@
+use std::ops::Range;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use regex::Captures;
+
+use crate::frontend::{
+    app::{App, Mode, VisualKind},
+    search::{self, Search},
+    window::{Selection, Window},
+};
+
+use super::{keymap::KeyChord, operator::Operator};
+
+/// A range of lines given as one based line numbers, both inclusive.
+#[derive(Copy, Clone)]
+struct LineRange {
+    first: usize,
+    last: usize,
+}
+
+/// Returns the number of lines, without the empty line after a trailing line break.
+fn line_count(sw: &Window) -> usize {
+    let len = sw.text().len_chars();
+    if len > 0 && sw.text().char(len - 1) == '\n' {
+        sw.text().len_lines() - 1
+    } else {
+        sw.text().len_lines()
+    }
+}
+
+fn current_line(sw: &Window) -> usize {
+    sw.text()
+        .char_to_line(sw.cursor_char_index.min(sw.text().len_chars()))
+        + 1
+}
+
+fn parse_number(s: &str) -> Option<(usize, &str)> {
+    let digits = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    let number = s[..digits].parse().ok()?;
+    Some((number, &s[digits..]))
+}
+
+/// Parses a line number like `.`, `$`, `12`, `'<` or `'>`, followed by offsets like `+3` or `-`.
+/// Only an offset is relative to the current line.
+fn parse_address<'a>(s: &'a str, sw: &Window) -> Result<(Option<usize>, &'a str), String> {
+    let (mut line, mut rest) = match s.chars().next() {
+        Some('.') => (Some(current_line(sw)), &s[1..]),
+        Some('$') => (Some(line_count(sw)), &s[1..]),
+        Some('\'') => {
+            let (first, last) = sw.visual_marks.ok_or_else(|| "Mark not set".to_string())?;
+            let mark = match s[1..].chars().next() {
+                Some('<') => first,
+                Some('>') => last,
+                _ => return Err(format!("Unknown mark in {s}")),
+            };
+            let mark = mark.min(sw.text().len_chars());
+            (Some(sw.text().char_to_line(mark) + 1), &s[2..])
+        }
+        Some(c) if c.is_ascii_digit() => {
+            let (number, rest) = parse_number(s).ok_or_else(|| format!("Invalid line in {s}"))?;
+            (Some(number), rest)
+        }
+        _ => (None, s),
+    };
+    while let Some(sign) = rest.chars().next().filter(|c| *c == '+' || *c == '-') {
+        let (offset, after) = parse_number(&rest[1..]).unwrap_or((1, &rest[1..]));
+        let base = line.unwrap_or_else(|| current_line(sw));
+        line = Some(if sign == '+' {
+            base + offset
+        } else {
+            base.checked_sub(offset)
+                .ok_or_else(|| "Invalid range".to_string())?
+        });
+        rest = after;
+    }
+    Ok((line, rest))
+}
+
+/// Parses `%`, `address` or `address,address` at the start of a command.
+fn parse_range<'a>(s: &'a str, sw: &Window) -> Result<(Option<LineRange>, &'a str), String> {
+    if let Some(rest) = s.strip_prefix('%') {
+        let range = LineRange {
+            first: 1,
+            last: line_count(sw),
+        };
+        return Ok((Some(range), rest));
+    }
+    let (first, rest) = parse_address(s, sw)?;
+    let Some(first) = first else {
+        return Ok((None, s));
+    };
+    let last = match rest.strip_prefix([',', ';']) {
+        Some(rest) => match parse_address(rest, sw)? {
+            (Some(last), rest) => (last, rest),
+            (None, rest) => (current_line(sw), rest),
+        },
+        None => (first, rest),
+    };
+    let (last, rest) = last;
+    let (first, last) = (first.min(last).max(1), first.max(last).max(1));
+    if last > line_count(sw) {
+        return Err("Invalid range".to_string());
+    }
+    Ok((Some(LineRange { first, last }), rest))
+}
+
+/// Runs `input` if it is a command that works on a range of lines, like `:s` or `:5`.
+/// Returns whether the input was handled, otherwise it is an ordinary command.
+pub fn execute(app: &mut App, input: &str) -> bool {
+    let Some(sw) = app.selected_window() else {
+        return false;
+    };
+    let (range, rest) = match parse_range(input.trim_start(), sw) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            app.log.log(format!("Error: {e}"));
+            return true;
+        }
+    };
+    let rest = rest.trim_start();
+    let name_length = rest
+        .find(|c: char| !c.is_ascii_alphabetic())
+        .unwrap_or(rest.len());
+    let (name, args) = rest.split_at(name_length);
+    let current = LineRange {
+        first: current_line(sw),
+        last: current_line(sw),
+    };
+    let whole_text = LineRange {
+        first: 1,
+        last: line_count(sw),
+    };
+
+    let known = matches!(
+        name,
+        "s" | "substitute"
+            | "g"
+            | "global"
+            | "v"
+            | "vglobal"
+            | "d"
+            | "delete"
+            | "m"
+            | "move"
+            | "t"
+            | "co"
+            | "copy"
+            | "norm"
+            | "normal"
+    );
+    match (name, range) {
+        ("", Some(range)) => {
+            goto_line(app, range.last);
+            return true;
+        }
+        (_, Some(_)) if !known => {
+            app.log.log(format!("Error: {name} doesn't take a range"));
+            return true;
+        }
+        _ if !known => return false,
+        _ => {}
+    }
+
+    // The whole command is undone at once
+    let window = app.selected_window;
+    app.edit_windows[window].begin_change();
+    let result = match name {
+        "s" | "substitute" => substitute(app, range.unwrap_or(current), args),
+        "g" | "global" => global(app, range.unwrap_or(whole_text), args, false),
+        "v" | "vglobal" => global(app, range.unwrap_or(whole_text), args, true),
+        "d" | "delete" => delete(app, range.unwrap_or(current), args),
+        "m" | "move" => move_lines(app, range.unwrap_or(current), args),
+        "t" | "co" | "copy" => copy_lines(app, range.unwrap_or(current), args),
+        _ => normal(app, range.unwrap_or(current), args),
+    };
+    if let Some(sw) = app.edit_windows.get_mut(window) {
+        sw.end_change();
+    }
+    if let Err(e) = result {
+        app.log.log(format!("Error: {e}"));
+    }
+    true
+}
+
+fn goto_line(app: &mut App, line: usize) {
+    if let Some(sw) = app.selected_window_mut() {
+        let line_index = line.clamp(1, line_count(sw)) - 1;
+        let indent = sw
+            .text()
+            .line(line_index)
+            .chars()
+            .take_while(|c| *c == ' ' || *c == '\t')
+            .count();
+        sw.cursor_char_index =
+            (sw.text().line_to_char(line_index) + indent).min(sw.text().len_chars().max(1) - 1);
+        sw.desired_column = None;
+    }
+}
+
+fn line_selection(sw: &Window, range: LineRange) -> Selection {
+    let chars = sw.text().line_to_char(range.first - 1)..sw.text().line_to_char(range.last);
+    Selection {
+        kind: VisualKind::Line,
+        ranges: vec![chars],
+    }
+}
+
+fn selected_window(app: &mut App) -> Result<&mut Window, String> {
+    app.edit_windows
+        .get_mut(app.selected_window)
+        .ok_or_else(|| "No open windows".to_string())
+}
+
+/// `:d [x]`, deletes the lines into register `x` or the unnamed register.
+fn delete(app: &mut App, range: LineRange, args: &str) -> Result<(), String> {
+    let register = match args.trim() {
+        "" => None,
+        name => {
+            let mut chars = name.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) if crate::frontend::register::Registers::is_valid_name(c) => {
+                    Some(c)
+                }
+                _ => return Err(format!("Invalid register {name}")),
+            }
+        }
+    };
+    let selection = line_selection(selected_window(app)?, range);
+    Operator::Delete.apply(app, selection, register);
+    Ok(())
+}
+
+/// Parses the line that `:m` and `:t` put the lines below, 0 puts them above the first line.
+fn parse_destination(sw: &Window, args: &str) -> Result<usize, String> {
+    match parse_address(args.trim(), sw)? {
+        (Some(line), "") if line <= line_count(sw) => Ok(line),
+        (Some(_), "") => Err("Invalid address".to_string()),
+        _ => Err(format!("Invalid address {}", args.trim())),
+    }
+}
+
+/// Returns the text of the lines, always ending with a line break.
+fn lines_text(sw: &Window, range: LineRange) -> String {
+    let mut text = sw
+        .text()
+        .slice(sw.text().line_to_char(range.first - 1)..sw.text().line_to_char(range.last))
+        .to_string();
+    if !text.ends_with('\n') {
+        text.push('\n');
+    }
+    text
+}
+
+/// Inserts `text`, which ends with a line break, so that it starts at line `line_index`.
+fn insert_lines(sw: &mut Window, line_index: usize, text: &str) {
+    let len = sw.text().len_chars();
+    if line_index < line_count(sw) {
+        sw.insert(sw.text().line_to_char(line_index), text);
+    } else if len == 0 || sw.text().char(len - 1) == '\n' {
+        sw.insert(len, text);
+    } else {
+        // The last line has no line break to insert after
+        sw.insert(
+            len,
+            &format!("\n{}", text.strip_suffix('\n').unwrap_or(text)),
+        );
+    }
+}
+
+/// `:m {address}`, moves the lines below the line `address`.
+fn move_lines(app: &mut App, range: LineRange, args: &str) -> Result<(), String> {
+    let sw = selected_window(app)?;
+    let destination = parse_destination(sw, args)?;
+    if destination >= range.first && destination < range.last {
+        return Err("Cannot move a range of lines into itself".to_string());
+    }
+    let text = lines_text(sw, range);
+    let moved_lines = range.last - range.first + 1;
+    super::operator::delete(sw, &line_selection(sw, range), false);
+    let line_index = if destination >= range.last {
+        destination - moved_lines
+    } else {
+        destination
+    };
+    insert_lines(sw, line_index, &text);
+    sw.cursor_char_index = sw.text().line_to_char(line_index + moved_lines - 1);
+    Ok(())
+}
+
+/// `:t {address}`, copies the lines below the line `address`.
+fn copy_lines(app: &mut App, range: LineRange, args: &str) -> Result<(), String> {
+    let sw = selected_window(app)?;
+    let destination = parse_destination(sw, args)?;
+    let text = lines_text(sw, range);
+    insert_lines(sw, destination, &text);
+    sw.cursor_char_index = sw
+        .text()
+        .line_to_char(destination + range.last - range.first);
+    Ok(())
+}
+
+/// Splits `s` at the first `separator` that isn't escaped with a backslash. The escaped
+/// separators in the first part lose their backslash, other escapes are kept.
+fn split_at_separator(s: &str, separator: char) -> (String, Option<&str>) {
+    let mut part = String::new();
+    let mut chars = s.char_indices();
+    while let Some((i, c)) = chars.next() {
+        if c == separator {
+            return (part, Some(&s[i + c.len_utf8()..]));
+        }
+        if c == '\\' {
+            match chars.next() {
+                Some((_, next)) if next == separator => part.push(next),
+                Some((_, next)) => {
+                    part.push(c);
+                    part.push(next);
+                }
+                None => part.push(c),
+            }
+        } else {
+            part.push(c);
+        }
+    }
+    (part, None)
+}
+
+/// Returns the pattern or the last search pattern if it is empty and remembers it for `n`.
+fn search_pattern(app: &mut App, pattern: String) -> Result<String, String> {
+    if !pattern.is_empty() {
+        app.search = Some(Search {
+            pattern: pattern.clone(),
+            backward: false,
+        });
+        return Ok(pattern);
+    }
+    app.search
+        .as_ref()
+        .map(|s| s.pattern.clone())
+        .ok_or_else(|| "No previous search pattern".to_string())
+}
+
+/// Expands the replacement of `:s`. `&` and `\0` stand for the whole match, `\1` to `\9` for
+/// capture groups and `\n` and `\t` for a line break and a tab.
+fn expand_replacement(replacement: &str, captures: &Captures) -> String {
+    let mut expanded = String::new();
+    let mut chars = replacement.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '&' => expanded.push_str(&captures[0]),
+            '\\' => match chars.next() {
+                Some(digit @ '0'..='9') => {
+                    let group = digit.to_digit(10).unwrap_or(0) as usize;
+                    if let Some(m) = captures.get(group) {
+                        expanded.push_str(m.as_str());
+                    }
+                }
+                Some('n') => expanded.push('\n'),
+                Some('t') => expanded.push('\t'),
+                Some(other) => expanded.push(other),
+                None => expanded.push('\\'),
+            },
+            c => expanded.push(c),
+        }
+    }
+    expanded
+}
+
+/// A `:s` whose replacements are confirmed one by one.
+pub struct Substitution {
+    /// The ranges of the matches in the text before any replacement and what replaces them
+    replacements: Vec<(Range<usize>, String)>,
+    next: usize,
+    /// How much the replacements so far moved the following text
+    offset: isize,
+    replaced: usize,
+}
+
+impl Substitution {
+    fn shifted(&self, range: &Range<usize>) -> Range<usize> {
+        let shift = |i: usize| (i as isize + self.offset) as usize;
+        shift(range.start)..shift(range.end)
+    }
+
+    /// Returns the match that is asked about right now.
+    pub fn current_match(&self) -> Option<Range<usize>> {
+        let (range, _) = self.replacements.get(self.next)?;
+        Some(self.shifted(range))
+    }
+
+    pub fn current_replacement(&self) -> Option<&str> {
+        self.replacements
+            .get(self.next)
+            .map(|(_, replacement)| replacement.as_str())
+    }
+
+    fn replace_next(&mut self, sw: &mut Window) {
+        let Some((range, replacement)) = self.replacements.get(self.next) else {
+            return;
+        };
+        let range = self.shifted(range);
+        sw.remove(range.clone());
+        sw.insert(range.start, replacement);
+        self.offset += replacement.chars().count() as isize - range.len() as isize;
+        self.replaced += 1;
+        self.next += 1;
+    }
+}
+
+/// `:s/pattern/replacement/flags` with the flags `g` for every match in a line, `c` to confirm
+/// each replacement and `i` and `I` to ignore or respect case.
+fn substitute(app: &mut App, range: LineRange, args: &str) -> Result<(), String> {
+    let separator = args
+        .chars()
+        .next()
+        .filter(|c| !c.is_alphanumeric() && !c.is_whitespace() && *c != '\\')
+        .ok_or_else(|| "Missing pattern, use :s/pattern/replacement/".to_string())?;
+    let (pattern, rest) = split_at_separator(&args[separator.len_utf8()..], separator);
+    let (replacement, flags) = match rest {
+        Some(rest) => {
+            let (replacement, flags) = split_at_separator(rest, separator);
+            (replacement, flags.unwrap_or("").trim())
+        }
+        None => (String::new(), ""),
+    };
+    let mut global = false;
+    let mut confirm = false;
+    let mut ignore_case = None;
+    for flag in flags.chars() {
+        match flag {
+            'g' => global = true,
+            'c' => confirm = true,
+            'i' => ignore_case = Some(true),
+            'I' => ignore_case = Some(false),
+            _ => return Err(format!("Unknown flag {flag}")),
+        }
+    }
+    let pattern = search_pattern(app, pattern)?;
+    let regex = match ignore_case {
+        Some(ignore_case) => search::build_regex_ignoring_case(&pattern, ignore_case),
+        None => search::build_regex(&pattern),
+    }
+    .map_err(|e| format!("Invalid pattern {pattern}: {e}"))?;
+
+    let sw = selected_window(app)?;
+    let mut replacements = Vec::new();
+    for line_index in range.first - 1..range.last {
+        let line_start = sw.text().line_to_char(line_index);
+        let line: String = sw
+            .text()
+            .line(line_index)
+            .chars()
+            .take_while(|c| *c != '\n')
+            .collect();
+        for captures in regex.captures_iter(&line) {
+            let m = &captures.get(0).expect("Capture group 0 is the whole match");
+            let start = line_start + line[..m.start()].chars().count();
+            let end = start + m.as_str().chars().count();
+            replacements.push((start..end, expand_replacement(&replacement, &captures)));
+            if !global {
+                break;
+            }
+        }
+    }
+    if replacements.is_empty() {
+        return Err(format!("Pattern not found: {pattern}"));
+    }
+    let mut substitution = Substitution {
+        replacements,
+        next: 0,
+        offset: 0,
+        replaced: 0,
+    };
+    if confirm {
+        // The change stays open until all replacements are confirmed
+        sw.begin_change();
+        sw.cursor_char_index = substitution.current_match().map_or(0, |r| r.start);
+        app.current_mode = Mode::ConfirmSubstitute { substitution };
+        return Ok(());
+    }
+    while substitution.current_match().is_some() {
+        substitution.replace_next(sw);
+    }
+    finish_substitution(app, substitution);
+    Ok(())
+}
+
+fn finish_substitution(app: &mut App, substitution: Substitution) {
+    let Some(sw) = app.selected_window_mut() else {
+        return;
+    };
+    if substitution.replaced > 0 {
+        let (last, _) = &substitution.replacements[substitution.next - 1];
+        let position = substitution.shifted(last).start.min(sw.text().len_chars());
+        sw.cursor_char_index = sw.text().line_to_char(sw.text().char_to_line(position));
+    }
+    app.log
+        .log(format!("{} substitutions", substitution.replaced));
+}
+
+/// Handles the keys while a `:s///c` asks whether to replace a match: `y` replaces it, `n`
+/// skips it, `a` replaces it and all following, `l` replaces it and stops and `q` stops.
+pub fn process_keys_confirm(event: KeyEvent, app: &mut App) -> bool {
+    if let KeyEventKind::Press = event.kind {
+        let Mode::ConfirmSubstitute { substitution } = &mut app.current_mode else {
+            app.log.log("Error: Not confirming a substitution");
+            return false;
+        };
+        let Some(sw) = app.edit_windows.get_mut(app.selected_window) else {
+            app.current_mode = Mode::Normal;
+            return false;
+        };
+        let mut finished = false;
+        match event.code {
+            KeyCode::Char('y') => substitution.replace_next(sw),
+            KeyCode::Char('l') => {
+                substitution.replace_next(sw);
+                finished = true;
+            }
+            KeyCode::Char('n') => substitution.next += 1,
+            KeyCode::Char('a') => {
+                while substitution.current_match().is_some() {
+                    substitution.replace_next(sw);
+                }
+            }
+            KeyCode::Char('q') | KeyCode::Esc => finished = true,
+            _ => return false,
+        }
+        match substitution.current_match() {
+            Some(range) if !finished => sw.cursor_char_index = range.start,
+            _ => {
+                sw.end_change();
+                if let Mode::ConfirmSubstitute { substitution } =
+                    std::mem::replace(&mut app.current_mode, Mode::Normal)
+                {
+                    finish_substitution(app, substitution);
+                }
+            }
+        }
+    }
+    false
+}
+
+/// Runs `f` with the cursor at the start of each of the zero based `lines`. Lines that `f`
+/// moves are followed and deleted lines are skipped.
+fn for_each_line(app: &mut App, lines: Vec<usize>, mut f: impl FnMut(&mut App)) {
+    let window = app.selected_window;
+    let sw = &app.edit_windows[window];
+    let mut positions: Vec<Option<usize>> = lines
+        .into_iter()
+        .map(|line| Some(sw.text().line_to_char(line)))
+        .collect();
+    for i in 0..positions.len() {
+        let Some(position) = positions[i] else {
+            continue;
+        };
+        let Some(sw) = app.edit_windows.get_mut(window) else {
+            return;
+        };
+        app.selected_window = window;
+        sw.cursor_char_index = position.min(sw.text().len_chars().max(1) - 1);
+        let edits_before = sw.buffer().history.open_edits().len();
+        f(app);
+        let Some(sw) = app.edit_windows.get(window) else {
+            return;
+        };
+        let buffer = sw.buffer();
+        for edit in buffer
+            .history
+            .open_edits()
+            .get(edits_before..)
+            .unwrap_or(&[])
+        {
+            for position in positions[i + 1..].iter_mut() {
+                *position = position.and_then(|p| edit.shift_position(p));
+            }
+        }
+    }
+}
+
+/// `:g/pattern/command` runs the command on every line matching the pattern, `:v` and `:g!`
+/// on every line that doesn't match.
+fn global(app: &mut App, range: LineRange, args: &str, invert: bool) -> Result<(), String> {
+    let (args, invert) = match args.strip_prefix('!') {
+        Some(args) => (args, !invert),
+        None => (args, invert),
+    };
+    let separator = args
+        .chars()
+        .next()
+        .filter(|c| !c.is_alphanumeric() && !c.is_whitespace() && *c != '\\')
+        .ok_or_else(|| "Missing pattern, use :g/pattern/command".to_string())?;
+    let (pattern, command) = split_at_separator(&args[separator.len_utf8()..], separator);
+    let command = command.unwrap_or("").trim_start().to_string();
+    if command.is_empty() {
+        return Err("Missing command, use :g/pattern/command".to_string());
+    }
+    let command_name: String = command
+        .trim_start_matches(|c: char| !c.is_ascii_alphabetic())
+        .chars()
+        .take_while(char::is_ascii_alphabetic)
+        .collect();
+    if matches!(command_name.as_str(), "g" | "global" | "v" | "vglobal") {
+        return Err("Cannot nest :g".to_string());
+    }
+    let pattern = search_pattern(app, pattern)?;
+    let regex =
+        search::build_regex(&pattern).map_err(|e| format!("Invalid pattern {pattern}: {e}"))?;
+    let sw = selected_window(app)?;
+    let lines: Vec<usize> = (range.first - 1..range.last)
+        .filter(|line_index| {
+            let line = sw.text().line(*line_index).to_string();
+            regex.is_match(line.trim_end_matches('\n')) != invert
+        })
+        .collect();
+    for_each_line(app, lines, |app| {
+        super::command::run_command(app, &command);
+    });
+    Ok(())
+}
+
+/// `:normal keys` types the keys in normal mode on every line. Special keys are written like in
+/// mappings, `<Esc>` or `<C-r>`, and insert mode is left at the end.
+fn normal(app: &mut App, range: LineRange, args: &str) -> Result<(), String> {
+    let keys = KeyChord::parse_sequence(args.strip_prefix(' ').unwrap_or(args))?;
+    let lines = (range.first - 1..range.last).collect();
+    for_each_line(app, lines, |app| {
+        app.current_mode = Mode::Normal;
+        for key in &keys {
+            super::process_keys(KeyEvent::new(key.code, key.modifiers), app);
+        }
+        app.pending_keys.clear();
+        app.pending_keys_since = None;
+        match app.current_mode {
+            Mode::Insert => {
+                super::process_keys(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE), app);
+            }
+            Mode::Normal => {}
+            _ => app.current_mode = Mode::Normal,
+        }
+    });
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::keys::test_util::{app_with_text, cursor, feed_keys, text};
+    use crate::log::Severity;
+
+    const LINES: &str = "one\ntwo\nthree\nfour\nfive\n";
+
+    fn has_errors(app: &crate::frontend::app::App) -> bool {
+        app.log
+            .take_entries()
+            .any(|(severity, _)| severity == Severity::Error)
+    }
+
+    #[test]
+    fn delete_line_ranges() {
+        let mut app = app_with_text(LINES);
+        feed_keys(&mut app, ":2,3d<CR>");
+        assert_eq!(text(&app), "one\nfour\nfive\n");
+
+        let mut app = app_with_text(LINES);
+        feed_keys(&mut app, "j:.,+2d<CR>");
+        assert_eq!(text(&app), "one\nfive\n");
+
+        let mut app = app_with_text(LINES);
+        feed_keys(&mut app, ":$d<CR>");
+        assert_eq!(text(&app), "one\ntwo\nthree\nfour\n");
+
+        let mut app = app_with_text(LINES);
+        feed_keys(&mut app, ":%d<CR>");
+        assert_eq!(text(&app), "");
+        assert!(!has_errors(&app));
+    }
+
+    #[test]
+    fn visual_marks_range() {
+        let mut app = app_with_text(LINES);
+        feed_keys(&mut app, "jVj<Esc>:'<lt>,'>d<CR>");
+        assert_eq!(text(&app), "one\nfour\nfive\n");
+    }
+
+    #[test]
+    fn invalid_ranges_leave_the_text_alone() {
+        let mut app = app_with_text(LINES);
+        feed_keys(&mut app, ":3,9d<CR>");
+        assert_eq!(text(&app), LINES);
+        assert!(has_errors(&app));
+    }
+
+    #[test]
+    fn line_number_moves_the_cursor() {
+        let mut app = app_with_text(LINES);
+        feed_keys(&mut app, ":3<CR>");
+        assert_eq!(cursor(&app), 8);
+    }
+
+    #[test]
+    fn substitute_with_flags() {
+        let mut app = app_with_text("a a\na a\n");
+        feed_keys(&mut app, ":%s/a/b/g<CR>");
+        assert_eq!(text(&app), "b b\nb b\n");
+
+        let mut app = app_with_text("a a\na a\n");
+        feed_keys(&mut app, ":%s/a/b<CR>");
+        assert_eq!(text(&app), "b a\nb a\n");
+    }
+
+    #[test]
+    fn substitute_with_escaped_separator() {
+        let mut app = app_with_text("a a\n");
+        feed_keys(&mut app, r":s/a/x\/y/g<CR>");
+        assert_eq!(text(&app), "x/y x/y\n");
+        assert!(!has_errors(&app));
+    }
+
+    #[test]
+    fn substitute_is_undone_at_once() {
+        let mut app = app_with_text(LINES);
+        feed_keys(&mut app, ":%s/o/0/g<CR>u");
+        assert_eq!(text(&app), LINES);
+    }
+}
@@ -1,48 +1,90 @@
-use crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
+use std::time::Instant;
+
+use crossterm::event::{KeyEvent, KeyEventKind};
 
 use crate::frontend::app::{App, Mode};
 
-pub fn process_keys_insert(event: KeyEvent, app: &mut App) -> bool {
-    if let KeyEventKind::Press = event.kind {
-        #[allow(clippy::single_match)]
-        match event.code {
-            KeyCode::Enter => {
-                if let Some(sw) = app.selected_window_mut() {
-                    sw.text.insert_char(sw.cursor_char_index, '\n');
-                    sw.cursor_char_index += 1;
-                }
-            }
-            KeyCode::Tab => {
-                if let Some(sw) = app.selected_window_mut() {
-                    sw.text.insert(sw.cursor_char_index, "    ");
-                    sw.cursor_char_index += 4;
+use super::keymap::{Binding, KeyChord, Resolved};
+
+pub const DEFAULT_BINDINGS: &[(&str, Binding)] = &[
+    ("<CR>", Binding::Insert(InsertAction::Newline)),
+    ("<Tab>", Binding::Insert(InsertAction::Indent)),
+    ("<BS>", Binding::Insert(InsertAction::DeleteBackward)),
+    ("<Esc>", Binding::Insert(InsertAction::Exit)),
+];
+
+/// What keys other than plain characters do in insert mode.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum InsertAction {
+    Newline,
+    Indent,
+    DeleteBackward,
+    Exit,
+}
+
+impl InsertAction {
+    pub fn run(&self, app: &mut App) {
+        match self {
+            InsertAction::Newline => insert_text(app, "\n"),
+            InsertAction::Indent => {
+                if let Some(indent) = app.selected_window().map(|sw| sw.indent_unit()) {
+                    insert_text(app, &indent);
                 }
             }
-            KeyCode::Backspace => {
+            InsertAction::DeleteBackward => {
                 if let Some(sw) = app.selected_window_mut() {
                     if sw.cursor_char_index > 0 {
-                        sw.text
-                            .remove((sw.cursor_char_index - 1)..sw.cursor_char_index);
+                        sw.remove((sw.cursor_char_index - 1)..sw.cursor_char_index);
                         sw.cursor_char_index -= 1;
                     }
                 }
             }
-            KeyCode::Esc => {
+            InsertAction::Exit => {
                 if let Some(sw) = app.selected_window_mut() {
-                    sw.cursor_char_index = sw.cursor_char_index.min(sw.text.len_chars().max(1) - 1);
+                    sw.end_change();
+                    sw.cursor_char_index =
+                        sw.cursor_char_index.min(sw.text().len_chars().max(1) - 1);
                 }
                 app.current_mode = Mode::Normal;
             }
-            KeyCode::Char(c) => {
-                if let Some(sw) = app.selected_window_mut() {
-                    sw.text.insert_char(sw.cursor_char_index, c);
-                    sw.modified = true;
-                    sw.cursor_char_index += 1;
+        }
+    }
+}
+
+fn insert_text(app: &mut App, text: &str) {
+    if let Some(sw) = app.selected_window_mut() {
+        sw.insert(sw.cursor_char_index, text);
+        sw.cursor_char_index += text.chars().count();
+    }
+}
+
+pub fn process_keys_insert(event: KeyEvent, app: &mut App) -> bool {
+    if let KeyEventKind::Press = event.kind {
+        app.pending_keys.push(KeyChord::from_event(event));
+        app.pending_keys_since = Some(Instant::now());
+        resolve_pending_keys(app, false);
+    }
+    false
+}
+
+/// Runs the bindings the pending keys form and inserts the keys that aren't bound. Keys that
+/// may start a longer binding wait for the next key unless `force` is set.
+pub fn resolve_pending_keys(app: &mut App, force: bool) {
+    while !app.pending_keys.is_empty() {
+        match app.keymaps.insert.resolve(&mut app.pending_keys, force) {
+            Resolved::Binding(binding) => {
+                if let Binding::Insert(action) = binding {
+                    action.run(app);
+                }
+                if !matches!(app.current_mode, Mode::Insert) {
+                    // The rest of the keys were typed for insert mode
+                    app.pending_keys.clear();
                 }
-                app.queue_selected_window_highlight_refresh();
             }
-            _ => {}
+            Resolved::Char(c) => insert_text(app, c.encode_utf8(&mut [0; 4])),
+            Resolved::Ignored => {}
+            Resolved::Pending => return,
         }
     }
-    false
+    app.pending_keys_since = None;
 }
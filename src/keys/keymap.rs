@@ -0,0 +1,668 @@
+use std::fmt::Display;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::frontend::{
+    language::SyntaxObject,
+    layout::{PaneDirection, SplitDirection},
+};
+
+use super::{
+    action::Action,
+    command::LineAction,
+    dialog::DialogAction,
+    insert::InsertAction,
+    motion::{FindKind, Motion},
+    operator::Operator,
+};
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct KeyChord {
+    pub code: KeyCode,
+    pub modifiers: KeyModifiers,
+}
+
+impl KeyChord {
+    pub fn from_event(event: KeyEvent) -> Self {
+        let mut modifiers = event.modifiers;
+        // Shift is already part of the character itself
+        if let KeyCode::Char(_) = event.code {
+            modifiers.remove(KeyModifiers::SHIFT);
+        }
+        KeyChord {
+            code: event.code,
+            modifiers,
+        }
+    }
+
+    pub fn char(c: char) -> Self {
+        KeyChord {
+            code: KeyCode::Char(c),
+            modifiers: KeyModifiers::NONE,
+        }
+    }
+
+    /// Returns the typed character if this chord has no modifiers. AltGr arrives as Ctrl+Alt,
+    /// so the characters typed with it count too.
+    pub fn as_char(&self) -> Option<char> {
+        let altgr = KeyModifiers::CONTROL | KeyModifiers::ALT;
+        match self.code {
+            KeyCode::Char(c) if self.modifiers.is_empty() || self.modifiers == altgr => Some(c),
+            _ => None,
+        }
+    }
+
+    /// Parses vim style key notation like `gg`, `<C-r>` or `<Esc>`.
+    pub fn parse_sequence(s: &str) -> Result<Vec<KeyChord>, String> {
+        let mut chords = Vec::new();
+        let mut rest = s;
+        while let Some(c) = rest.chars().next() {
+            if c == '<' {
+                if let Some(end) = rest.find('>') {
+                    if end > 1 {
+                        chords.push(KeyChord::parse_special(&rest[1..end])?);
+                        rest = &rest[end + 1..];
+                        continue;
+                    }
+                }
+            }
+            chords.push(KeyChord::char(c));
+            rest = &rest[c.len_utf8()..];
+        }
+        Ok(chords)
+    }
+
+    fn parse_special(name: &str) -> Result<KeyChord, String> {
+        let mut modifiers = KeyModifiers::NONE;
+        let mut key = name;
+        while key.len() > 2 && key.as_bytes()[1] == b'-' {
+            match key.as_bytes()[0].to_ascii_uppercase() {
+                b'C' => modifiers.insert(KeyModifiers::CONTROL),
+                b'A' | b'M' => modifiers.insert(KeyModifiers::ALT),
+                b'S' => modifiers.insert(KeyModifiers::SHIFT),
+                _ => return Err(format!("Unknown modifier in <{name}>")),
+            }
+            key = &key[2..];
+        }
+        let code = match key.to_ascii_lowercase().as_str() {
+            "esc" => KeyCode::Esc,
+            "cr" | "enter" | "return" => KeyCode::Enter,
+            "tab" => KeyCode::Tab,
+            "bs" | "backspace" => KeyCode::Backspace,
+            "del" | "delete" => KeyCode::Delete,
+            "space" => KeyCode::Char(' '),
+            "lt" => KeyCode::Char('<'),
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            "pageup" => KeyCode::PageUp,
+            "pagedown" => KeyCode::PageDown,
+            _ => {
+                let mut chars = key.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => KeyCode::Char(c),
+                    _ => return Err(format!("Unknown key <{name}>")),
+                }
+            }
+        };
+        if let KeyCode::Char(c) = code {
+            if modifiers.contains(KeyModifiers::SHIFT) {
+                modifiers.remove(KeyModifiers::SHIFT);
+                return Ok(KeyChord {
+                    code: KeyCode::Char(c.to_ascii_uppercase()),
+                    modifiers,
+                });
+            }
+        }
+        Ok(KeyChord { code, modifiers })
+    }
+}
+
+impl Display for KeyChord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self.code {
+            KeyCode::Char('<') => "lt".to_string(),
+            KeyCode::Char(' ') => "Space".to_string(),
+            KeyCode::Char(c) if self.modifiers.is_empty() => return write!(f, "{c}"),
+            KeyCode::Char(c) => c.to_string(),
+            KeyCode::Esc => "Esc".to_string(),
+            KeyCode::Enter => "CR".to_string(),
+            KeyCode::Tab => "Tab".to_string(),
+            KeyCode::Backspace => "BS".to_string(),
+            KeyCode::Delete => "Del".to_string(),
+            code => format!("{code:?}"),
+        };
+        write!(f, "<")?;
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            write!(f, "C-")?;
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            write!(f, "A-")?;
+        }
+        if self.modifiers.contains(KeyModifiers::SHIFT) {
+            write!(f, "S-")?;
+        }
+        write!(f, "{name}>")
+    }
+}
+
+pub fn display_sequence(keys: &[KeyChord]) -> String {
+    keys.iter().map(|k| k.to_string()).collect()
+}
+
+/// What a key sequence can be bound to.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum Binding {
+    Motion(Motion),
+    /// `f`, `t`, `F` and `T`, which take the char to find as the next key
+    FindChar(FindKind),
+    Operator(Operator),
+    Action(Action),
+    /// Only available in insert mode
+    Insert(InsertAction),
+    /// Only available in dialogs like `:maps` or the window picker
+    Dialog(DialogAction),
+    /// Only available in the lines typed in command and search mode, the file finder and the
+    /// filter of the window picker
+    Line(LineAction),
+}
+
+/// The names bindings go by in the config file and in `:map`.
+pub const NAMED_BINDINGS: &[(&str, Binding)] = &[
+    ("left", Binding::Motion(Motion::Left)),
+    ("right", Binding::Motion(Motion::Right)),
+    ("down", Binding::Motion(Motion::Down)),
+    ("up", Binding::Motion(Motion::Up)),
+    (
+        "word_forward",
+        Binding::Motion(Motion::WordForward { big: false }),
+    ),
+    (
+        "big_word_forward",
+        Binding::Motion(Motion::WordForward { big: true }),
+    ),
+    (
+        "word_backward",
+        Binding::Motion(Motion::WordBackward { big: false }),
+    ),
+    (
+        "big_word_backward",
+        Binding::Motion(Motion::WordBackward { big: true }),
+    ),
+    ("word_end", Binding::Motion(Motion::WordEnd { big: false })),
+    (
+        "big_word_end",
+        Binding::Motion(Motion::WordEnd { big: true }),
+    ),
+    ("line_start", Binding::Motion(Motion::LineStart)),
+    ("first_non_blank", Binding::Motion(Motion::FirstNonBlank)),
+    ("line_end", Binding::Motion(Motion::LineEnd)),
+    ("first_line", Binding::Motion(Motion::FirstLine)),
+    ("last_line", Binding::Motion(Motion::LastLine)),
+    (
+        "paragraph_forward",
+        Binding::Motion(Motion::ParagraphForward),
+    ),
+    (
+        "paragraph_backward",
+        Binding::Motion(Motion::ParagraphBackward),
+    ),
+    ("matching_bracket", Binding::Motion(Motion::MatchingBracket)),
+    ("find_char", Binding::FindChar(FindKind::To)),
+    ("till_char", Binding::FindChar(FindKind::Till)),
+    (
+        "find_char_backward",
+        Binding::FindChar(FindKind::ToBackward),
+    ),
+    (
+        "till_char_backward",
+        Binding::FindChar(FindKind::TillBackward),
+    ),
+    (
+        "repeat_find",
+        Binding::Motion(Motion::RepeatFind { reverse: false }),
+    ),
+    (
+        "repeat_find_reverse",
+        Binding::Motion(Motion::RepeatFind { reverse: true }),
+    ),
+    ("next_function", syntax_node(SyntaxObject::Function, true)),
+    (
+        "previous_function",
+        syntax_node(SyntaxObject::Function, false),
+    ),
+    ("next_class", syntax_node(SyntaxObject::Class, true)),
+    ("previous_class", syntax_node(SyntaxObject::Class, false)),
+    ("next_parameter", syntax_node(SyntaxObject::Parameter, true)),
+    (
+        "previous_parameter",
+        syntax_node(SyntaxObject::Parameter, false),
+    ),
+    ("next_comment", syntax_node(SyntaxObject::Comment, true)),
+    (
+        "previous_comment",
+        syntax_node(SyntaxObject::Comment, false),
+    ),
+    ("next_block", syntax_node(SyntaxObject::Block, true)),
+    ("previous_block", syntax_node(SyntaxObject::Block, false)),
+    ("delete", Binding::Operator(Operator::Delete)),
+    ("yank", Binding::Operator(Operator::Yank)),
+    ("change", Binding::Operator(Operator::Change)),
+    ("indent", Binding::Operator(Operator::Indent)),
+    ("dedent", Binding::Operator(Operator::Dedent)),
+    ("toggle_case", Binding::Operator(Operator::ToggleCase)),
+    ("lowercase", Binding::Operator(Operator::Lowercase)),
+    ("uppercase", Binding::Operator(Operator::Uppercase)),
+    ("command_mode", Binding::Action(Action::EnterCommandMode)),
+    ("insert", Binding::Action(Action::Insert)),
+    ("append", Binding::Action(Action::Append)),
+    ("open_line_below", Binding::Action(Action::OpenLineBelow)),
+    ("open_line_above", Binding::Action(Action::OpenLineAbove)),
+    ("delete_char", Binding::Action(Action::DeleteChar)),
+    ("substitute_char", Binding::Action(Action::SubstituteChar)),
+    ("put_after", Binding::Action(Action::PutAfter)),
+    ("put_before", Binding::Action(Action::PutBefore)),
+    ("undo", Binding::Action(Action::Undo)),
+    ("redo", Binding::Action(Action::Redo)),
+    ("visual", Binding::Action(Action::VisualChar)),
+    ("visual_line", Binding::Action(Action::VisualLine)),
+    ("visual_block", Binding::Action(Action::VisualBlock)),
+    ("exit_visual", Binding::Action(Action::ExitVisual)),
+    (
+        "swap_selection_ends",
+        Binding::Action(Action::SwapSelectionEnds),
+    ),
+    ("next_window", Binding::Action(Action::NextWindow)),
+    ("previous_window", Binding::Action(Action::PreviousWindow)),
+    ("show_windows", Binding::Action(Action::ShowWindows)),
+    ("next_tab", Binding::Action(Action::NextTab)),
+    ("previous_tab", Binding::Action(Action::PreviousTab)),
+    ("find_file", Binding::Action(Action::FindFile)),
+    (
+        "split",
+        Binding::Action(Action::SplitPane(SplitDirection::Horizontal)),
+    ),
+    (
+        "vsplit",
+        Binding::Action(Action::SplitPane(SplitDirection::Vertical)),
+    ),
+    (
+        "focus_left",
+        Binding::Action(Action::FocusPane(PaneDirection::Left)),
+    ),
+    (
+        "focus_down",
+        Binding::Action(Action::FocusPane(PaneDirection::Down)),
+    ),
+    (
+        "focus_up",
+        Binding::Action(Action::FocusPane(PaneDirection::Up)),
+    ),
+    (
+        "focus_right",
+        Binding::Action(Action::FocusPane(PaneDirection::Right)),
+    ),
+    ("focus_next_pane", Binding::Action(Action::FocusNextPane)),
+    ("close_pane", Binding::Action(Action::ClosePane)),
+    ("only_pane", Binding::Action(Action::OnlyPane)),
+    ("equalize_panes", Binding::Action(Action::EqualizePanes)),
+    ("grow_height", resize_pane(SplitDirection::Horizontal, true)),
+    (
+        "shrink_height",
+        resize_pane(SplitDirection::Horizontal, false),
+    ),
+    ("grow_width", resize_pane(SplitDirection::Vertical, true)),
+    ("shrink_width", resize_pane(SplitDirection::Vertical, false)),
+    ("search_forward", Binding::Action(Action::SearchForward)),
+    ("search_backward", Binding::Action(Action::SearchBackward)),
+    ("search_next", Binding::Action(Action::SearchNext)),
+    ("search_previous", Binding::Action(Action::SearchPrevious)),
+    ("newline", Binding::Insert(InsertAction::Newline)),
+    ("insert_indent", Binding::Insert(InsertAction::Indent)),
+    (
+        "delete_backward",
+        Binding::Insert(InsertAction::DeleteBackward),
+    ),
+    ("exit_insert", Binding::Insert(InsertAction::Exit)),
+    ("cancel", Binding::Dialog(DialogAction::Cancel)),
+    ("confirm", Binding::Dialog(DialogAction::Confirm)),
+    ("select_next", Binding::Dialog(DialogAction::SelectNext)),
+    (
+        "select_previous",
+        Binding::Dialog(DialogAction::SelectPrevious),
+    ),
+    ("filter", Binding::Dialog(DialogAction::Filter)),
+    ("close_window", Binding::Dialog(DialogAction::CloseWindow)),
+    (
+        "force_close_window",
+        Binding::Dialog(DialogAction::ForceCloseWindow),
+    ),
+    ("cancel", Binding::Line(LineAction::Cancel)),
+    ("confirm", Binding::Line(LineAction::Confirm)),
+    ("left", Binding::Line(LineAction::Left)),
+    ("right", Binding::Line(LineAction::Right)),
+    ("delete_backward", Binding::Line(LineAction::DeleteBackward)),
+    ("select_next", Binding::Line(LineAction::SelectNext)),
+    ("select_previous", Binding::Line(LineAction::SelectPrevious)),
+];
+
+const fn syntax_node(object: SyntaxObject, forward: bool) -> Binding {
+    Binding::Motion(Motion::SyntaxNode { object, forward })
+}
+
+const fn resize_pane(along: SplitDirection, grow: bool) -> Binding {
+    Binding::Action(Action::ResizePane { along, grow })
+}
+
+impl Binding {
+    /// Finds the binding called `name` among the ones `mode` can use. Some names, like
+    /// `confirm`, stand for a different binding in each mode.
+    pub fn by_name(mode: MapMode, name: &str) -> Option<Binding> {
+        NAMED_BINDINGS
+            .iter()
+            .find(|(n, binding)| *n == name && binding.available_in(mode))
+            .map(|(_, binding)| *binding)
+    }
+
+    pub fn available_in(&self, mode: MapMode) -> bool {
+        match self {
+            Binding::Insert(_) => mode == MapMode::Insert,
+            Binding::Dialog(_) => mode == MapMode::Dialog,
+            Binding::Line(_) => mode == MapMode::Command,
+            _ => matches!(mode, MapMode::Normal | MapMode::Visual),
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        NAMED_BINDINGS
+            .iter()
+            .find(|(_, binding)| binding == self)
+            .map(|(name, _)| *name)
+            .unwrap_or("unnamed")
+    }
+}
+
+pub struct Keymap {
+    bindings: Vec<(Vec<KeyChord>, Binding)>,
+}
+
+pub enum Lookup {
+    /// The binding and how many keys it used
+    Found(Binding, usize),
+    Pending,
+    NotFound,
+}
+
+/// What the next of the pending keys do in a mode without counts and operators.
+pub enum Resolved {
+    Binding(Binding),
+    /// A key that isn't bound and types a character
+    Char(char),
+    /// A key that isn't bound and does nothing
+    Ignored,
+    /// The keys may still become a longer binding
+    Pending,
+}
+
+impl Keymap {
+    pub fn bindings(&self) -> &[(Vec<KeyChord>, Binding)] {
+        &self.bindings
+    }
+
+    /// Binds `keys` to `binding`, replacing what they were bound to before.
+    pub fn bind(&mut self, keys: Vec<KeyChord>, binding: Binding) {
+        match self
+            .bindings
+            .iter_mut()
+            .find(|(sequence, _)| *sequence == keys)
+        {
+            Some((_, old)) => *old = binding,
+            None => self.bindings.push((keys, binding)),
+        }
+    }
+
+    /// Removes the binding of `keys`, returns false if there was none.
+    pub fn unbind(&mut self, keys: &[KeyChord]) -> bool {
+        let len = self.bindings.len();
+        self.bindings.retain(|(sequence, _)| sequence != keys);
+        self.bindings.len() != len
+    }
+
+    pub fn new(defaults: &[(&str, Binding)]) -> Self {
+        Keymap {
+            bindings: defaults
+                .iter()
+                .map(|(keys, binding)| {
+                    (
+                        KeyChord::parse_sequence(keys).expect("Invalid default key sequence"),
+                        *binding,
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    /// Finds the longest binding that `keys` starts with. As long as a longer binding could
+    /// still match, the lookup stays pending unless `force` is set.
+    pub fn lookup(
+        &self,
+        keys: &[KeyChord],
+        accept: impl Fn(&Binding) -> bool,
+        force: bool,
+    ) -> Lookup {
+        let mut found: Option<(Binding, usize)> = None;
+        let mut longer_exists = false;
+        for (sequence, binding) in self.bindings.iter().filter(|(_, b)| accept(b)) {
+            if keys.starts_with(sequence) {
+                if found.is_none_or(|(_, len)| sequence.len() > len) {
+                    found = Some((*binding, sequence.len()));
+                }
+            } else if sequence.starts_with(keys) {
+                longer_exists = true;
+            }
+        }
+        match found {
+            _ if longer_exists && !force => Lookup::Pending,
+            Some((binding, len)) => Lookup::Found(binding, len),
+            None if longer_exists => Lookup::Pending,
+            None => Lookup::NotFound,
+        }
+    }
+
+    /// Takes the longest binding that `keys` start with off them, or the first key if it
+    /// isn't bound. As long as a longer binding could still match, the keys stay pending
+    /// unless `force` is set.
+    pub fn resolve(&self, keys: &mut Vec<KeyChord>, force: bool) -> Resolved {
+        match self.lookup(keys, |_| true, force) {
+            Lookup::Found(binding, used) => {
+                keys.drain(..used);
+                Resolved::Binding(binding)
+            }
+            Lookup::Pending if !force => Resolved::Pending,
+            Lookup::Pending | Lookup::NotFound => match keys.remove(0).code {
+                // Characters are typed even with modifiers that aren't bound, like AltGr
+                KeyCode::Char(c) => Resolved::Char(c),
+                _ => Resolved::Ignored,
+            },
+        }
+    }
+}
+
+/// The modes that have their own keymap.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum MapMode {
+    Normal,
+    Visual,
+    Insert,
+    Dialog,
+    /// Typing a line, in command and search mode, the file finder and the window filter
+    Command,
+}
+
+impl MapMode {
+    pub const ALL: [MapMode; 5] = [
+        MapMode::Normal,
+        MapMode::Visual,
+        MapMode::Insert,
+        MapMode::Dialog,
+        MapMode::Command,
+    ];
+
+    pub fn by_name(name: &str) -> Option<MapMode> {
+        MapMode::ALL.into_iter().find(|mode| mode.name() == name)
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            MapMode::Normal => "normal",
+            MapMode::Visual => "visual",
+            MapMode::Insert => "insert",
+            MapMode::Dialog => "dialog",
+            MapMode::Command => "command",
+        }
+    }
+}
+
+pub struct Keymaps {
+    pub normal: Keymap,
+    pub visual: Keymap,
+    pub insert: Keymap,
+    pub dialog: Keymap,
+    pub command: Keymap,
+}
+
+impl Keymaps {
+    pub fn new() -> Self {
+        Keymaps {
+            normal: Keymap::new(
+                &[
+                    super::motion::DEFAULT_BINDINGS,
+                    super::normal::DEFAULT_BINDINGS,
+                ]
+                .concat(),
+            ),
+            visual: Keymap::new(
+                &[
+                    super::motion::DEFAULT_BINDINGS,
+                    super::visual::DEFAULT_BINDINGS,
+                ]
+                .concat(),
+            ),
+            insert: Keymap::new(super::insert::DEFAULT_BINDINGS),
+            dialog: Keymap::new(super::dialog::DEFAULT_BINDINGS),
+            command: Keymap::new(super::command::DEFAULT_BINDINGS),
+        }
+    }
+
+    pub fn get(&self, mode: MapMode) -> &Keymap {
+        match mode {
+            MapMode::Normal => &self.normal,
+            MapMode::Visual => &self.visual,
+            MapMode::Insert => &self.insert,
+            MapMode::Dialog => &self.dialog,
+            MapMode::Command => &self.command,
+        }
+    }
+
+    fn get_mut(&mut self, mode: MapMode) -> &mut Keymap {
+        match mode {
+            MapMode::Normal => &mut self.normal,
+            MapMode::Visual => &mut self.visual,
+            MapMode::Insert => &mut self.insert,
+            MapMode::Dialog => &mut self.dialog,
+            MapMode::Command => &mut self.command,
+        }
+    }
+
+    /// Binds the key sequence `keys` to the binding called `name` in `mode`.
+    pub fn map(&mut self, mode: MapMode, keys: &str, name: &str) -> Result<(), String> {
+        let chords = KeyChord::parse_sequence(keys)?;
+        if chords.is_empty() {
+            return Err("Missing keys to map".to_string());
+        }
+        let binding = Binding::by_name(mode, name).ok_or_else(|| {
+            if NAMED_BINDINGS.iter().any(|(n, _)| *n == name) {
+                format!("{name} can't be used in {} mode", mode.name())
+            } else {
+                format!("Unknown binding {name}")
+            }
+        })?;
+        self.get_mut(mode).bind(chords, binding);
+        Ok(())
+    }
+
+    pub fn unmap(&mut self, mode: MapMode, keys: &str) -> Result<(), String> {
+        let chords = KeyChord::parse_sequence(keys)?;
+        if self.get_mut(mode).unbind(&chords) {
+            Ok(())
+        } else {
+            Err(format!("{keys} is not mapped in {} mode", mode.name()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::frontend::app::Mode;
+    use crate::keys::test_util::{app_with_text, cursor, feed_keys, text};
+
+    use super::{Binding, MapMode};
+
+    #[test]
+    fn altgr_characters_are_typed() {
+        let mut app = app_with_text("a@b\n");
+        feed_keys(&mut app, "i<C-A-{><Esc>");
+        assert_eq!(text(&app), "{a@b\n");
+        feed_keys(&mut app, "0f<C-A-@>");
+        assert_eq!(cursor(&app), 2);
+    }
+
+    #[test]
+    fn unbound_characters_with_modifiers_are_typed() {
+        let mut app = app_with_text("\n");
+        feed_keys(&mut app, "i<A-x><Esc>");
+        assert_eq!(text(&app), "x\n");
+    }
+
+    #[test]
+    fn command_line_keys_can_be_mapped() {
+        let mut app = app_with_text("abc\n");
+        feed_keys(&mut app, ":s/b/x<BS>y<CR>");
+        assert_eq!(text(&app), "ayc\n");
+        feed_keys(&mut app, ":cmap <lt>C-h> delete_backward<CR>");
+        feed_keys(&mut app, ":s/y/x<C-h>z<CR>");
+        assert_eq!(text(&app), "azc\n");
+    }
+
+    #[test]
+    fn dialog_keys_can_be_mapped() {
+        let mut app = app_with_text("\n");
+        feed_keys(&mut app, ":maps<CR>q");
+        assert!(matches!(app.current_mode, Mode::Normal));
+        feed_keys(&mut app, ":dmap x cancel<CR>:maps<CR>x");
+        assert!(matches!(app.current_mode, Mode::Normal));
+    }
+
+    #[test]
+    fn names_are_looked_up_per_mode() {
+        assert!(Binding::by_name(MapMode::Dialog, "confirm").is_some());
+        assert!(Binding::by_name(MapMode::Command, "confirm").is_some());
+        assert!(
+            Binding::by_name(MapMode::Dialog, "confirm")
+                != Binding::by_name(MapMode::Command, "confirm")
+        );
+        assert!(Binding::by_name(MapMode::Normal, "confirm").is_none());
+        let mut app = app_with_text("\n");
+        assert!(app
+            .keymaps
+            .map(MapMode::Normal, "x", "exit_insert")
+            .is_err());
+        assert!(app
+            .keymaps
+            .map(MapMode::Insert, "<C-c>", "exit_insert")
+            .is_ok());
+    }
+}
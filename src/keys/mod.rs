@@ -1,17 +1,94 @@
+use std::time::Duration;
+
 use crossterm::event::KeyEvent;
 
 use crate::frontend::app::{App, Mode};
 
+mod action;
 mod command;
 mod dialog;
+pub mod ex;
 mod insert;
+pub mod keymap;
+pub mod motion;
 mod normal;
+mod operator;
+pub mod pending;
+mod search;
+mod textobject;
+mod visual;
 
 pub fn process_keys(event: KeyEvent, app: &mut App) -> bool {
+    if let Some(sw) = app.selected_window_mut() {
+        sw.follow_buffer();
+    }
     match &app.current_mode {
         Mode::Normal => normal::process_keys_normal(event, app),
         Mode::Insert => insert::process_keys_insert(event, app),
+        Mode::Visual { .. } => visual::process_keys_visual(event, app),
         Mode::Dialog { .. } => dialog::process_keys_dialog(event, app),
-        Mode::Command { .. } => command::process_keys_dialog(event, app),
+        Mode::Command { .. } => command::process_keys_command(event, app),
+        Mode::Search { .. } => search::process_keys_search(event, app),
+        Mode::ConfirmSubstitute { .. } => ex::process_keys_confirm(event, app),
+    }
+}
+
+/// Gives up waiting for more keys once the pending keys timed out. An ambiguous sequence
+/// like `g` with both `g` and `gg` bound then runs the shorter command. Returns whether that
+/// quits ted, like `process_keys`.
+pub fn process_pending_keys_timeout(app: &mut App) -> bool {
+    let Some(since) = app.pending_keys_since else {
+        return false;
+    };
+    if since.elapsed() < Duration::from_millis(app.options.timeoutlen as u64) {
+        return false;
+    }
+    match app.current_mode {
+        Mode::Normal => normal::resolve_pending_keys(app, true),
+        Mode::Visual { .. } => visual::resolve_pending_keys(app, true),
+        Mode::Insert => insert::resolve_pending_keys(app, true),
+        Mode::Dialog { .. } => dialog::resolve_pending_keys(app, true),
+        Mode::Search { .. } => search::resolve_pending_keys(app, true),
+        Mode::Command { .. } => return command::resolve_pending_keys(app, true),
+        _ => {
+            app.pending_keys.clear();
+            app.pending_keys_since = None;
+        }
+    }
+    false
+}
+
+/// Helpers for tests that drive the editor with key sequences.
+#[cfg(test)]
+pub(crate) mod test_util {
+    use crossterm::event::KeyEvent;
+    use ropey::Rope;
+
+    use crate::frontend::app::App;
+
+    use super::keymap::KeyChord;
+
+    /// An app with a single window showing `text`, the cursor at its start.
+    pub fn app_with_text(text: &str) -> App {
+        let mut app = App::new();
+        let window_index = app.create_empty_window();
+        app.edit_windows[window_index].buffer_mut().text = Rope::from_str(text);
+        app.selected_window = window_index;
+        app
+    }
+
+    /// Feeds vim style key notation like `d$` or `ifoo<Esc>` to the app.
+    pub fn feed_keys(app: &mut App, keys: &str) {
+        for chord in KeyChord::parse_sequence(keys).unwrap() {
+            super::process_keys(KeyEvent::new(chord.code, chord.modifiers), app);
+        }
+    }
+
+    pub fn text(app: &App) -> String {
+        app.edit_windows[app.selected_window].text().to_string()
+    }
+
+    pub fn cursor(app: &App) -> usize {
+        app.edit_windows[app.selected_window].cursor_char_index
     }
 }
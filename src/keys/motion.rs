@@ -0,0 +1,598 @@
+use crate::frontend::{
+    app::App,
+    language::SyntaxObject,
+    window::{char_display_width, Window},
+};
+
+use super::{keymap::Binding, textobject::syntax_object_starts};
+
+pub const DEFAULT_BINDINGS: &[(&str, Binding)] = &[
+    ("h", Binding::Motion(Motion::Left)),
+    ("j", Binding::Motion(Motion::Down)),
+    ("k", Binding::Motion(Motion::Up)),
+    ("l", Binding::Motion(Motion::Right)),
+    ("w", Binding::Motion(Motion::WordForward { big: false })),
+    ("W", Binding::Motion(Motion::WordForward { big: true })),
+    ("b", Binding::Motion(Motion::WordBackward { big: false })),
+    ("B", Binding::Motion(Motion::WordBackward { big: true })),
+    ("e", Binding::Motion(Motion::WordEnd { big: false })),
+    ("E", Binding::Motion(Motion::WordEnd { big: true })),
+    ("0", Binding::Motion(Motion::LineStart)),
+    ("^", Binding::Motion(Motion::FirstNonBlank)),
+    ("$", Binding::Motion(Motion::LineEnd)),
+    ("gg", Binding::Motion(Motion::FirstLine)),
+    ("G", Binding::Motion(Motion::LastLine)),
+    ("}", Binding::Motion(Motion::ParagraphForward)),
+    ("{", Binding::Motion(Motion::ParagraphBackward)),
+    ("%", Binding::Motion(Motion::MatchingBracket)),
+    ("f", Binding::FindChar(FindKind::To)),
+    ("t", Binding::FindChar(FindKind::Till)),
+    ("F", Binding::FindChar(FindKind::ToBackward)),
+    ("T", Binding::FindChar(FindKind::TillBackward)),
+    (";", Binding::Motion(Motion::RepeatFind { reverse: false })),
+    (",", Binding::Motion(Motion::RepeatFind { reverse: true })),
+    ("]f", syntax_node(SyntaxObject::Function, true)),
+    ("[f", syntax_node(SyntaxObject::Function, false)),
+    ("]c", syntax_node(SyntaxObject::Class, true)),
+    ("[c", syntax_node(SyntaxObject::Class, false)),
+    ("]a", syntax_node(SyntaxObject::Parameter, true)),
+    ("[a", syntax_node(SyntaxObject::Parameter, false)),
+    ("]/", syntax_node(SyntaxObject::Comment, true)),
+    ("[/", syntax_node(SyntaxObject::Comment, false)),
+    ("]o", syntax_node(SyntaxObject::Block, true)),
+    ("[o", syntax_node(SyntaxObject::Block, false)),
+];
+
+const fn syntax_node(object: SyntaxObject, forward: bool) -> Binding {
+    Binding::Motion(Motion::SyntaxNode { object, forward })
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum Motion {
+    Left,
+    Right,
+    Down,
+    Up,
+    /// `w` goes to the start of the next word, `W` to the start of the next WORD
+    WordForward {
+        big: bool,
+    },
+    WordBackward {
+        big: bool,
+    },
+    WordEnd {
+        big: bool,
+    },
+    LineStart,
+    FirstNonBlank,
+    /// `$`, goes to the last char of the line or of the line count - 1 lines below
+    LineEnd,
+    /// `gg`, goes to the line given by the count or the first line
+    FirstLine,
+    /// `G`, goes to the line given by the count or the last line
+    LastLine,
+    ParagraphForward,
+    ParagraphBackward,
+    /// `%`, goes to the bracket matching the next bracket on the line
+    MatchingBracket,
+    Find(FindChar),
+    /// `;` and `,`, repeat the last `f`, `t`, `F` or `T`
+    RepeatFind {
+        reverse: bool,
+    },
+    /// `]f`, `[f` and the like, go to the start of the next or previous node of the syntax tree
+    SyntaxNode {
+        object: SyntaxObject,
+        forward: bool,
+    },
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum FindKind {
+    To,
+    Till,
+    ToBackward,
+    TillBackward,
+}
+
+impl FindKind {
+    fn reversed(self) -> FindKind {
+        match self {
+            FindKind::To => FindKind::ToBackward,
+            FindKind::Till => FindKind::TillBackward,
+            FindKind::ToBackward => FindKind::To,
+            FindKind::TillBackward => FindKind::Till,
+        }
+    }
+}
+
+/// A search for a char on the current line, as done by `f`, `t`, `F` and `T`.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct FindChar {
+    pub kind: FindKind,
+    pub c: char,
+}
+
+/// Where a motion ends up and how an operator treats the text it moved over.
+pub struct Target {
+    pub char_index: usize,
+    /// Operators act on whole lines
+    pub linewise: bool,
+    /// The char at the target is part of the range an operator acts on
+    pub inclusive: bool,
+    /// The visual column that following vertical motions try to keep
+    pub desired_column: Option<usize>,
+}
+
+impl Motion {
+    /// Returns where the motion goes from the cursor of `sw`, or `None` if it fails, like a
+    /// search for a char that isn't on the line. `last_find` is repeated by `;` and `,`.
+    pub fn target(
+        &self,
+        sw: &Window,
+        count: Option<usize>,
+        last_find: Option<FindChar>,
+    ) -> Option<Target> {
+        let n = count.unwrap_or(1);
+        let cursor = sw.cursor_char_index.min(sw.text().len_chars());
+        let line_index = sw.text().char_to_line(cursor);
+        let mut desired_column = None;
+        let char_index = match *self {
+            Motion::Left => cursor
+                .saturating_sub(n)
+                .max(sw.text().line_to_char(line_index)),
+            Motion::Right => cursor.saturating_add(n).min(line_last_char(sw, line_index)),
+            Motion::Down | Motion::Up => {
+                let target_line = if let Motion::Down = self {
+                    line_index.saturating_add(n).min(sw.text().len_lines() - 1)
+                } else {
+                    line_index.saturating_sub(n)
+                };
+                let column = sw
+                    .desired_column
+                    .unwrap_or_else(|| sw.visual_column(cursor));
+                desired_column = Some(column);
+                char_at_column(sw, target_line, column)
+            }
+            Motion::WordForward { big } => repeat(cursor, n, |i| next_word_start(sw, i, big)),
+            Motion::WordBackward { big } => repeat(cursor, n, |i| previous_word_start(sw, i, big)),
+            Motion::WordEnd { big } => repeat(cursor, n, |i| next_word_end(sw, i, big)),
+            Motion::LineStart => sw.text().line_to_char(line_index),
+            Motion::FirstNonBlank => first_non_blank(sw, line_index),
+            Motion::LineEnd => {
+                desired_column = Some(usize::MAX);
+                line_last_char(
+                    sw,
+                    line_index
+                        .saturating_add(n - 1)
+                        .min(sw.text().len_lines() - 1),
+                )
+            }
+            Motion::FirstLine => first_non_blank(sw, line_number(sw, count.unwrap_or(1))),
+            Motion::LastLine => {
+                first_non_blank(sw, line_number(sw, count.unwrap_or(sw.text().len_lines())))
+            }
+            Motion::ParagraphForward => repeat(cursor, n, |i| paragraph_boundary(sw, i, true)),
+            Motion::ParagraphBackward => repeat(cursor, n, |i| paragraph_boundary(sw, i, false)),
+            Motion::MatchingBracket => matching_bracket(sw, cursor)?,
+            Motion::Find(find) => find_char(sw, cursor, find, n, false)?,
+            Motion::RepeatFind { reverse } => {
+                let mut find = last_find?;
+                if reverse {
+                    find.kind = find.kind.reversed();
+                }
+                find_char(sw, cursor, find, n, true)?
+            }
+            Motion::SyntaxNode { object, forward } => {
+                let starts = syntax_object_starts(sw, object);
+                if forward {
+                    *starts.iter().filter(|i| **i > cursor).nth(n - 1)?
+                } else {
+                    *starts.iter().rev().filter(|i| **i < cursor).nth(n - 1)?
+                }
+            }
+        };
+        Some(Target {
+            char_index,
+            linewise: matches!(
+                self,
+                Motion::Down | Motion::Up | Motion::FirstLine | Motion::LastLine
+            ),
+            inclusive: match self {
+                Motion::WordEnd { .. } | Motion::MatchingBracket => true,
+                // On an empty line `$` lands on the line break, which `d$` must not delete
+                Motion::LineEnd => sw.text().get_char(char_index) != Some('\n'),
+                Motion::Find(find) => matches!(find.kind, FindKind::To | FindKind::Till),
+                Motion::RepeatFind { reverse } => last_find.is_some_and(|find| {
+                    matches!(find.kind, FindKind::To | FindKind::Till) != *reverse
+                }),
+                _ => false,
+            },
+            desired_column,
+        })
+    }
+
+    /// Like `target`, but with the special cases of vim for operators: `cw` only changes to
+    /// the end of the word and `dw` on the last word of a line doesn't join the next line.
+    pub fn operator_target(
+        &self,
+        sw: &Window,
+        count: Option<usize>,
+        last_find: Option<FindChar>,
+        change: bool,
+    ) -> Option<Target> {
+        let Motion::WordForward { big } = *self else {
+            return self.target(sw, count, last_find);
+        };
+        let cursor = sw.cursor_char_index;
+        if cursor >= sw.text().len_chars() {
+            return None;
+        }
+        if change && !sw.text().char(cursor).is_whitespace() {
+            let end = repeat(word_end(sw, cursor, big), count.unwrap_or(1) - 1, |i| {
+                next_word_end(sw, i, big)
+            });
+            return Some(Target {
+                char_index: end,
+                linewise: false,
+                inclusive: true,
+                desired_column: None,
+            });
+        }
+        let mut target = self.target(sw, count, last_find)?;
+        let line_end = sw.text().line_to_char(sw.text().char_to_line(cursor) + 1);
+        if target.char_index >= line_end && line_end > cursor + 1 {
+            target.char_index = line_end - 1;
+        }
+        Some(target)
+    }
+
+    /// Moves the cursor of the selected window. Remembers `f`, `t`, `F` and `T` for `;` and `,`.
+    pub fn move_cursor(&self, app: &mut App, count: Option<usize>) {
+        if let Motion::Find(find) = self {
+            app.last_find = Some(*find);
+        }
+        let Some(sw) = app.edit_windows.get_mut(app.selected_window) else {
+            return;
+        };
+        if let Some(target) = self.target(sw, count, app.last_find) {
+            sw.cursor_char_index = target.char_index.min(sw.text().len_chars().max(1) - 1);
+            sw.desired_column = target.desired_column;
+        }
+    }
+}
+
+/// What a char counts as for word motions and word text objects.
+#[derive(PartialEq, Eq)]
+pub(super) enum CharClass {
+    Newline,
+    Whitespace,
+    Word,
+    Punctuation,
+}
+
+/// Applies `step` up to `n` times, stopping early once it no longer moves.
+fn repeat(from: usize, n: usize, step: impl Fn(usize) -> usize) -> usize {
+    let mut position = from;
+    for _ in 0..n {
+        let next = step(position);
+        if next == position {
+            break;
+        }
+        position = next;
+    }
+    position
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Returns the class of `c`, with `big` every non-blank char is part of a word like for `W`.
+pub(super) fn char_class(c: char, big: bool) -> CharClass {
+    if c == '\n' {
+        CharClass::Newline
+    } else if c.is_whitespace() {
+        CharClass::Whitespace
+    } else if big || is_word_char(c) {
+        CharClass::Word
+    } else {
+        CharClass::Punctuation
+    }
+}
+
+fn is_empty_line_at(sw: &Window, char_index: usize) -> bool {
+    sw.text().char(char_index) == '\n'
+        && (char_index == 0 || sw.text().char(char_index - 1) == '\n')
+}
+
+fn next_word_start(sw: &Window, from: usize, big: bool) -> usize {
+    let len = sw.text().len_chars();
+    if from >= len {
+        return from;
+    }
+    let class = char_class(sw.text().char(from), big);
+    let mut i = from;
+    if class == CharClass::Word || class == CharClass::Punctuation {
+        while i < len && char_class(sw.text().char(i), big) == class {
+            i += 1;
+        }
+    }
+    // Skip the whitespace in between, an empty line counts as a word though
+    while i < len {
+        match char_class(sw.text().char(i), big) {
+            CharClass::Whitespace => i += 1,
+            CharClass::Newline if i == from || !is_empty_line_at(sw, i) => i += 1,
+            _ => break,
+        }
+    }
+    i
+}
+
+/// Returns the last char of the word at `from`.
+fn word_end(sw: &Window, from: usize, big: bool) -> usize {
+    let class = char_class(sw.text().char(from), big);
+    let mut i = from;
+    while i + 1 < sw.text().len_chars() && char_class(sw.text().char(i + 1), big) == class {
+        i += 1;
+    }
+    i
+}
+
+fn next_word_end(sw: &Window, from: usize, big: bool) -> usize {
+    let len = sw.text().len_chars();
+    let mut i = from + 1;
+    while i < len
+        && matches!(
+            char_class(sw.text().char(i), big),
+            CharClass::Whitespace | CharClass::Newline
+        )
+    {
+        i += 1;
+    }
+    if i >= len {
+        return len.max(1) - 1;
+    }
+    word_end(sw, i, big)
+}
+
+fn previous_word_start(sw: &Window, from: usize, big: bool) -> usize {
+    if from == 0 {
+        return 0;
+    }
+    let mut i = from - 1;
+    while i > 0 {
+        match char_class(sw.text().char(i), big) {
+            CharClass::Whitespace => i -= 1,
+            CharClass::Newline if !is_empty_line_at(sw, i) => i -= 1,
+            _ => break,
+        }
+    }
+    let class = char_class(sw.text().char(i), big);
+    if class == CharClass::Newline {
+        return i;
+    }
+    while i > 0 && char_class(sw.text().char(i - 1), big) == class {
+        i -= 1;
+    }
+    i
+}
+
+/// Returns the last char of `line_index` that isn't its line break.
+fn line_last_char(sw: &Window, line_index: usize) -> usize {
+    let line_start = sw.text().line_to_char(line_index);
+    let line_length = sw
+        .text()
+        .line(line_index)
+        .chars()
+        .take_while(|c| *c != '\n')
+        .count();
+    line_start + line_length.max(1) - 1
+}
+
+fn first_non_blank(sw: &Window, line_index: usize) -> usize {
+    let line_start = sw.text().line_to_char(line_index);
+    let indent = sw
+        .text()
+        .line(line_index)
+        .chars()
+        .take_while(|c| *c == ' ' || *c == '\t')
+        .count();
+    (line_start + indent).min(line_last_char(sw, line_index))
+}
+
+/// Returns the index of the one based `line_number`, clamped to the last line.
+fn line_number(sw: &Window, line_number: usize) -> usize {
+    line_number.saturating_sub(1).min(sw.text().len_lines() - 1)
+}
+
+/// Returns the char of `line_index` that is displayed at the visual `column`, or the last
+/// char of the line if it is too short.
+fn char_at_column(sw: &Window, line_index: usize, column: usize) -> usize {
+    let line_start = sw.text().line_to_char(line_index);
+    let mut current_column = 0;
+    for (i, c) in sw.text().line(line_index).chars().enumerate() {
+        if c == '\n' {
+            break;
+        }
+        current_column += char_display_width(c, current_column, sw.tab_width());
+        if current_column > column {
+            return line_start + i;
+        }
+    }
+    line_last_char(sw, line_index)
+}
+
+fn is_empty_line(sw: &Window, line_index: usize) -> bool {
+    sw.text().line(line_index).chars().all(|c| c == '\n')
+}
+
+/// Returns the start of the next (or previous) empty line after the paragraph the cursor is
+/// in, or the end (or start) of the text if there is none.
+fn paragraph_boundary(sw: &Window, from: usize, forward: bool) -> usize {
+    let last_line = sw.text().len_lines() - 1;
+    let mut line_index = sw.text().char_to_line(from);
+    let step = |line_index: usize| {
+        if forward {
+            (line_index < last_line).then(|| line_index + 1)
+        } else {
+            line_index.checked_sub(1)
+        }
+    };
+    let mut in_paragraph = !is_empty_line(sw, line_index);
+    loop {
+        let Some(next) = step(line_index) else {
+            return if forward {
+                sw.text().len_chars().max(1) - 1
+            } else {
+                0
+            };
+        };
+        line_index = next;
+        let line_start = sw.text().line_to_char(line_index);
+        if !is_empty_line(sw, line_index) {
+            in_paragraph = true;
+        } else if in_paragraph && line_start < sw.text().len_chars() {
+            // The empty line after a final line break is the end of the text, not a boundary
+            return line_start;
+        }
+    }
+}
+
+const BRACKETS: [(char, char); 3] = [('(', ')'), ('[', ']'), ('{', '}')];
+
+/// Finds the first bracket at or after the cursor on its line and returns the bracket
+/// matching it.
+fn matching_bracket(sw: &Window, cursor: usize) -> Option<usize> {
+    let line_end = line_last_char(sw, sw.text().char_to_line(cursor));
+    let (start, c) = (cursor..=line_end)
+        .filter(|i| *i < sw.text().len_chars())
+        .map(|i| (i, sw.text().char(i)))
+        .find(|(_, c)| BRACKETS.iter().any(|(open, close)| c == open || c == close))?;
+    let mut depth = 0usize;
+    if let Some((open, close)) = BRACKETS.iter().find(|(open, _)| *open == c) {
+        for (i, c) in sw.text().chars_at(start).enumerate() {
+            if c == *open {
+                depth += 1;
+            } else if c == *close {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(start + i);
+                }
+            }
+        }
+    } else {
+        let (open, close) = BRACKETS.iter().find(|(_, close)| *close == c)?;
+        let text = sw.text();
+        let mut chars = text.chars_at(start + 1);
+        let mut i = start + 1;
+        while let Some(c) = chars.prev() {
+            i -= 1;
+            if c == *close {
+                depth += 1;
+            } else if c == *open {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Returns where the `count`th occurrence of the char of `find` on the cursor line takes the
+/// cursor. A repeated `t` or `T` skips an occurrence right next to the cursor, so it doesn't
+/// get stuck in front of it.
+fn find_char(
+    sw: &Window,
+    cursor: usize,
+    find: FindChar,
+    count: usize,
+    repeated: bool,
+) -> Option<usize> {
+    let line_index = sw.text().char_to_line(cursor);
+    let line_start = sw.text().line_to_char(line_index);
+    let line_end = line_last_char(sw, line_index);
+    let skip = if repeated && matches!(find.kind, FindKind::Till | FindKind::TillBackward) {
+        1
+    } else {
+        0
+    };
+    let mut found = None;
+    match find.kind {
+        FindKind::To | FindKind::Till => {
+            let mut occurrences =
+                (cursor + 1 + skip..=line_end).filter(|i| sw.text().char(*i) == find.c);
+            found = occurrences.nth(count - 1);
+        }
+        FindKind::ToBackward | FindKind::TillBackward => {
+            if cursor >= line_start + skip {
+                let mut occurrences = (line_start..cursor - skip)
+                    .rev()
+                    .filter(|i| sw.text().char(*i) == find.c);
+                found = occurrences.nth(count - 1);
+            }
+        }
+    }
+    let found = found?;
+    Some(match find.kind {
+        FindKind::To | FindKind::ToBackward => found,
+        FindKind::Till => found - 1,
+        FindKind::TillBackward => found + 1,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::keys::test_util::{app_with_text, cursor, feed_keys, text};
+
+    #[test]
+    fn word_motions_take_a_count() {
+        let mut app = app_with_text("one two three four\n");
+        feed_keys(&mut app, "2w");
+        assert_eq!(cursor(&app), 8);
+        feed_keys(&mut app, "e");
+        assert_eq!(cursor(&app), 12);
+        feed_keys(&mut app, "2b");
+        assert_eq!(cursor(&app), 4);
+    }
+
+    #[test]
+    fn huge_counts_stop_at_the_end_of_the_text() {
+        for motion in ["w", "b", "e", "W", "}", "{"] {
+            let mut app = app_with_text("one two\n\nthree four\n");
+            feed_keys(&mut app, &format!("5l50{motion}"));
+            let expected = cursor(&app);
+            feed_keys(&mut app, &format!("0gg5l99999999999{motion}"));
+            assert_eq!(cursor(&app), expected, "{motion}");
+        }
+    }
+
+    #[test]
+    fn change_word_stops_at_the_end_of_the_word() {
+        let mut app = app_with_text("one two\n");
+        feed_keys(&mut app, "cwxy<Esc>");
+        assert_eq!(text(&app), "xy two\n");
+    }
+
+    #[test]
+    fn delete_to_line_end_keeps_empty_lines() {
+        let mut app = app_with_text("abc\n\ndef\n");
+        feed_keys(&mut app, "jd$");
+        assert_eq!(text(&app), "abc\n\ndef\n");
+        feed_keys(&mut app, "c$x<Esc>");
+        assert_eq!(text(&app), "abc\nx\ndef\n");
+        feed_keys(&mut app, "ggd$");
+        assert_eq!(text(&app), "\nx\ndef\n");
+    }
+
+    #[test]
+    fn line_end_takes_a_count() {
+        let mut app = app_with_text("abc\ndef\nghi\n");
+        feed_keys(&mut app, "2$");
+        assert_eq!(cursor(&app), 6);
+        feed_keys(&mut app, "gg99999999999$");
+        assert_eq!(cursor(&app), 11);
+    }
+}
@@ -1,142 +1,226 @@
-use crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
+use std::time::Instant;
+
+use crossterm::event::{KeyEvent, KeyEventKind};
 
 use crate::frontend::{
-    app::{App, Mode},
-    dialog::Dialog,
+    app::{App, VisualKind},
+    layout::{PaneDirection, SplitDirection},
+    window::{Selection, Window},
+};
+
+use super::{
+    action::Action,
+    keymap::{Binding, KeyChord},
+    motion::{FindChar, Motion},
+    operator::Operator,
+    pending::{self, Command, OperatorTarget, Parse, ParsedCommand},
 };
 
+pub const DEFAULT_BINDINGS: &[(&str, Binding)] = &[
+    ("d", Binding::Operator(Operator::Delete)),
+    ("y", Binding::Operator(Operator::Yank)),
+    ("c", Binding::Operator(Operator::Change)),
+    (">", Binding::Operator(Operator::Indent)),
+    ("<lt>", Binding::Operator(Operator::Dedent)),
+    ("g~", Binding::Operator(Operator::ToggleCase)),
+    ("gu", Binding::Operator(Operator::Lowercase)),
+    ("gU", Binding::Operator(Operator::Uppercase)),
+    (":", Binding::Action(Action::EnterCommandMode)),
+    ("i", Binding::Action(Action::Insert)),
+    ("a", Binding::Action(Action::Append)),
+    ("o", Binding::Action(Action::OpenLineBelow)),
+    ("O", Binding::Action(Action::OpenLineAbove)),
+    ("x", Binding::Action(Action::DeleteChar)),
+    ("s", Binding::Action(Action::SubstituteChar)),
+    ("p", Binding::Action(Action::PutAfter)),
+    ("P", Binding::Action(Action::PutBefore)),
+    ("u", Binding::Action(Action::Undo)),
+    ("<C-r>", Binding::Action(Action::Redo)),
+    ("v", Binding::Action(Action::VisualChar)),
+    ("V", Binding::Action(Action::VisualLine)),
+    ("<C-v>", Binding::Action(Action::VisualBlock)),
+    ("L", Binding::Action(Action::NextWindow)),
+    ("H", Binding::Action(Action::PreviousWindow)),
+    ("gw", Binding::Action(Action::ShowWindows)),
+    ("gt", Binding::Action(Action::NextTab)),
+    ("gT", Binding::Action(Action::PreviousTab)),
+    ("<C-p>", Binding::Action(Action::FindFile)),
+    (
+        "<C-w>s",
+        Binding::Action(Action::SplitPane(SplitDirection::Horizontal)),
+    ),
+    (
+        "<C-w>v",
+        Binding::Action(Action::SplitPane(SplitDirection::Vertical)),
+    ),
+    (
+        "<C-w>h",
+        Binding::Action(Action::FocusPane(PaneDirection::Left)),
+    ),
+    (
+        "<C-w>j",
+        Binding::Action(Action::FocusPane(PaneDirection::Down)),
+    ),
+    (
+        "<C-w>k",
+        Binding::Action(Action::FocusPane(PaneDirection::Up)),
+    ),
+    (
+        "<C-w>l",
+        Binding::Action(Action::FocusPane(PaneDirection::Right)),
+    ),
+    ("<C-w>w", Binding::Action(Action::FocusNextPane)),
+    ("<C-w><C-w>", Binding::Action(Action::FocusNextPane)),
+    ("<C-w>c", Binding::Action(Action::ClosePane)),
+    ("<C-w>q", Binding::Action(Action::ClosePane)),
+    ("<C-w>o", Binding::Action(Action::OnlyPane)),
+    ("<C-w>=", Binding::Action(Action::EqualizePanes)),
+    (
+        "<C-w>+",
+        Binding::Action(Action::ResizePane {
+            along: SplitDirection::Horizontal,
+            grow: true,
+        }),
+    ),
+    (
+        "<C-w>-",
+        Binding::Action(Action::ResizePane {
+            along: SplitDirection::Horizontal,
+            grow: false,
+        }),
+    ),
+    (
+        "<C-w>>",
+        Binding::Action(Action::ResizePane {
+            along: SplitDirection::Vertical,
+            grow: true,
+        }),
+    ),
+    (
+        "<C-w><lt>",
+        Binding::Action(Action::ResizePane {
+            along: SplitDirection::Vertical,
+            grow: false,
+        }),
+    ),
+    ("/", Binding::Action(Action::SearchForward)),
+    ("?", Binding::Action(Action::SearchBackward)),
+    ("n", Binding::Action(Action::SearchNext)),
+    ("N", Binding::Action(Action::SearchPrevious)),
+];
+
 pub fn process_keys_normal(event: KeyEvent, app: &mut App) -> bool {
     if let KeyEventKind::Press = event.kind {
-        #[allow(clippy::single_match)]
-        match event.code {
-            KeyCode::Char(c) => match c {
-                ':' => {
-                    app.current_mode = Mode::Command {
-                        buffer: String::new(),
-                        char_idx: 0,
-                    }
-                }
-                'a' => {
-                    if let Some(sw) = app.selected_window_mut() {
-                        if sw.cursor_char_index < sw.text.len_chars() {
-                            sw.cursor_char_index += 1;
-                        }
-                        app.current_mode = Mode::Insert;
-                    }
-                }
-                'i' => {
-                    if app.edit_windows.is_empty() {
-                        app.log.log("Error: No open windows");
-                    } else {
-                        app.current_mode = Mode::Insert;
-                    }
-                }
-                'x' => {
-                    if let Some(sw) = app.selected_window_mut() {
-                        if !(sw.text.len_chars() == 0
-                            || sw.cursor_char_index >= sw.text.len_chars())
-                        {
-                            sw.text.remove(sw.cursor_char_index..=sw.cursor_char_index);
-                            app.queue_selected_window_highlight_refresh();
-                        }
-                    }
-                }
-                's' => {
-                    if let Some(sw) = app.selected_window_mut() {
-                        if !(sw.text.len_chars() == 0
-                            || sw.cursor_char_index >= sw.text.len_chars())
-                        {
-                            sw.text.remove(sw.cursor_char_index..=sw.cursor_char_index);
-                            app.current_mode = Mode::Insert;
-                            app.queue_selected_window_highlight_refresh();
-                        }
-                    }
-                }
-                'O' => {
-                    if let Some(sw) = app.selected_window_mut() {
-                        let line_index = sw.text.char_to_line(sw.cursor_char_index);
-                        let idx = sw.text.line_to_char(line_index);
-                        sw.text.insert(idx, "\n");
-                        sw.cursor_char_index = sw.text.line_to_char(line_index);
+        app.pending_keys.push(KeyChord::from_event(event));
+        app.pending_keys_since = Some(Instant::now());
+        resolve_pending_keys(app, false);
+    }
+    false
+}
 
-                        app.current_mode = Mode::Insert;
-                        app.queue_selected_window_highlight_refresh();
-                    }
-                }
-                'o' => {
-                    if let Some(sw) = app.selected_window_mut() {
-                        let line_index = sw.text.char_to_line(sw.cursor_char_index);
-                        let line_slice = sw.text.line(line_index);
-                        let idx = sw.text.line_to_char(line_index) + line_slice.len_chars();
-                        sw.text.insert(idx, "\n");
-                        sw.cursor_char_index = sw.text.line_to_char(line_index + 1);
+/// Runs the pending keys once they form a complete command. With `force` set, an ambiguous
+/// sequence runs the shorter command instead of waiting for more keys.
+pub fn resolve_pending_keys(app: &mut App, force: bool) {
+    match pending::parse(&app.pending_keys, &app.keymaps.normal, false, force) {
+        Parse::Pending if !force => return,
+        Parse::Complete(parsed) => {
+            app.pending_keys.clear();
+            execute(app, parsed);
+        }
+        Parse::Pending | Parse::Invalid => {}
+    }
+    app.pending_keys.clear();
+    app.pending_keys_since = None;
+}
 
-                        app.current_mode = Mode::Insert;
-                        app.queue_selected_window_highlight_refresh();
-                    }
-                }
-                'G' => {
-                    if let Some(sw) = app.selected_window_mut() {
-                        if sw.text.len_chars() != 0 {
-                            sw.cursor_char_index = sw.text.len_chars() - 1;
-                        }
-                    }
-                }
-                'l' => {
-                    if let Some(sw) = app.selected_window_mut() {
-                        if sw.cursor_char_index + 1 >= sw.text.len_chars() {
-                            return false;
-                        }
-                        sw.cursor_char_index += 1;
-                    }
-                }
-                'h' => {
-                    if let Some(sw) = app.selected_window_mut() {
-                        if sw.cursor_char_index == 0 {
-                            return false;
-                        }
-                        sw.cursor_char_index -= 1;
-                    }
-                }
-                'j' => {
-                    if let Some(sw) = app.selected_window_mut() {
-                        let text = &mut sw.text;
-                        let current_line_index = text.char_to_line(sw.cursor_char_index);
-                        if current_line_index + 1 >= text.len_lines() {
-                            return false;
-                        }
-                        let start_of_next_line = text.line_to_char(current_line_index + 1);
+fn execute(app: &mut App, parsed: ParsedCommand) {
+    let ParsedCommand {
+        count,
+        register,
+        command,
+    } = parsed;
+    match command {
+        Command::Motion(motion) => motion.move_cursor(app, count),
+        Command::Action(action) => {
+            action.run(app, count, register);
+            forget_desired_column(app);
+        }
+        Command::OperatorWith(operator, target) => {
+            if let OperatorTarget::Motion(Motion::Find(find)) = target {
+                app.last_find = Some(find);
+            }
+            let Some(sw) = app.selected_window() else {
+                app.log.log("Error: No open windows");
+                return;
+            };
+            let Some(selection) = operator_selection(sw, operator, target, count, app.last_find)
+            else {
+                return;
+            };
+            operator.apply(app, selection, register);
+            forget_desired_column(app);
+        }
+        Command::Operator(_) | Command::TextObject(_) => {}
+    }
+}
 
-                        let line_offset =
-                            sw.cursor_char_index - text.line_to_char(current_line_index);
-                        let next_line_slice = text.line(current_line_index + 1);
-                        let new_offset = line_offset.min(next_line_slice.len_chars());
+fn forget_desired_column(app: &mut App) {
+    if let Some(sw) = app.selected_window_mut() {
+        sw.desired_column = None;
+    }
+}
 
-                        sw.cursor_char_index = start_of_next_line + new_offset;
-                    }
-                }
-                'k' => {
-                    if let Some(sw) = app.selected_window_mut() {
-                        let text = &mut sw.text;
-                        let current_line_index = text.char_to_line(sw.cursor_char_index);
-                        if current_line_index == 0 {
-                            return false;
-                        }
-                        let start_of_prev_line = text.line_to_char(current_line_index - 1);
-                        sw.cursor_char_index = start_of_prev_line;
-                    }
-                }
-                'L' => app.next_window(),
-                'H' => app.previous_window(),
-                'W' => {
-                    app.current_mode = Mode::Dialog {
-                        which_one: Dialog::Windows,
-                    }
+/// Returns the text `operator` acts on when it's used with `target`.
+fn operator_selection(
+    sw: &Window,
+    operator: Operator,
+    target: OperatorTarget,
+    count: Option<usize>,
+    last_find: Option<FindChar>,
+) -> Option<Selection> {
+    let cursor = sw.cursor_char_index;
+    Some(match target {
+        OperatorTarget::Lines => {
+            let line_index = sw.text().char_to_line(cursor);
+            let last_line = line_index
+                .saturating_add(count.unwrap_or(1) - 1)
+                .min(sw.text().len_lines() - 1);
+            line_selection(sw, line_index, last_line)
+        }
+        OperatorTarget::Motion(motion) => {
+            let change = matches!(operator, Operator::Change);
+            let target = motion.operator_target(sw, count, last_find, change)?;
+            let start = cursor.min(target.char_index);
+            let end = cursor.max(target.char_index);
+            if target.linewise {
+                line_selection(
+                    sw,
+                    sw.text().char_to_line(start),
+                    sw.text().char_to_line(end),
+                )
+            } else {
+                let end = if target.inclusive { end + 1 } else { end };
+                let range = start..end.min(sw.text().len_chars());
+                Selection {
+                    kind: VisualKind::Char,
+                    ranges: vec![range],
                 }
-                _ => {}
-            },
-            _ => {}
+            }
         }
+        OperatorTarget::TextObject(object) => {
+            let range = object.range(sw, count.unwrap_or(1))?;
+            Selection {
+                kind: VisualKind::Char,
+                ranges: vec![range],
+            }
+        }
+    })
+}
+
+fn line_selection(sw: &Window, first_line: usize, last_line: usize) -> Selection {
+    let range = sw.text().line_to_char(first_line)..sw.text().line_to_char(last_line + 1);
+    Selection {
+        kind: VisualKind::Line,
+        ranges: vec![range],
     }
-    false
 }
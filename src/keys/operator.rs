@@ -0,0 +1,178 @@
+use crate::frontend::{
+    app::{App, Mode, VisualKind},
+    register::{RegisterContent, RegisterKind},
+    window::{Selection, Window},
+};
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum Operator {
+    Delete,
+    Yank,
+    Change,
+    Indent,
+    Dedent,
+    ToggleCase,
+    Lowercase,
+    Uppercase,
+}
+
+impl Operator {
+    /// Applies the operator to the selection in the selected window.
+    pub fn apply(&self, app: &mut App, selection: Selection, register: Option<char>) {
+        let Some(sw) = app.edit_windows.get_mut(app.selected_window) else {
+            app.current_mode = Mode::Normal;
+            return;
+        };
+        let register_kind = match selection.kind {
+            VisualKind::Char => RegisterKind::Charwise,
+            VisualKind::Line => RegisterKind::Linewise,
+            VisualKind::Block => RegisterKind::Blockwise,
+        };
+        let mut next_mode = Mode::Normal;
+        match self {
+            Operator::Yank => {
+                let yanked = selected_text(sw, &selection);
+                sw.cursor_char_index = selection.start();
+                app.log
+                    .log(format!("Yanked {} characters", yanked.chars().count()));
+                app.registers
+                    .yank(register, RegisterContent::new(yanked, register_kind));
+            }
+            Operator::Delete => {
+                sw.begin_change();
+                let deleted = delete(sw, &selection, false);
+                sw.end_change();
+                app.registers
+                    .delete(register, RegisterContent::new(deleted, register_kind));
+            }
+            Operator::Change => {
+                sw.begin_change();
+                let deleted = delete(sw, &selection, true);
+                app.registers
+                    .delete(register, RegisterContent::new(deleted, register_kind));
+                next_mode = Mode::Insert;
+            }
+            Operator::Indent | Operator::Dedent => {
+                sw.begin_change();
+                let first_line = sw.text().char_to_line(selection.start());
+                let last_char = selection.ranges.last().map(|r| r.end).unwrap_or(0);
+                let last_line = sw
+                    .text()
+                    .char_to_line(last_char.saturating_sub(1).max(selection.start()));
+                for line_index in (first_line..=last_line).rev() {
+                    if let Operator::Indent = self {
+                        indent_line(sw, line_index);
+                    } else {
+                        dedent_line(sw, line_index);
+                    }
+                }
+                sw.end_change();
+                sw.cursor_char_index = sw.text().line_to_char(first_line);
+            }
+            Operator::ToggleCase | Operator::Lowercase | Operator::Uppercase => {
+                sw.begin_change();
+                for range in selection.ranges.iter().rev() {
+                    let original = sw.text().slice(range.clone()).to_string();
+                    let converted = self.convert_case(&original);
+                    if converted != original {
+                        sw.remove(range.clone());
+                        sw.insert(range.start, &converted);
+                    }
+                }
+                sw.end_change();
+                sw.cursor_char_index = selection.start();
+            }
+        }
+        if let Mode::Normal = next_mode {
+            sw.cursor_char_index = sw.cursor_char_index.min(sw.text().len_chars().max(1) - 1);
+        }
+        app.current_mode = next_mode;
+    }
+
+    fn convert_case(&self, s: &str) -> String {
+        match self {
+            Operator::Lowercase => s.to_lowercase(),
+            Operator::Uppercase => s.to_uppercase(),
+            _ => s
+                .chars()
+                .flat_map(|c| {
+                    if c.is_uppercase() {
+                        c.to_lowercase().collect::<Vec<char>>()
+                    } else {
+                        c.to_uppercase().collect::<Vec<char>>()
+                    }
+                })
+                .collect(),
+        }
+    }
+}
+
+pub fn selected_text(sw: &Window, selection: &Selection) -> String {
+    let mut text = selection
+        .ranges
+        .iter()
+        .map(|r| sw.text().slice(r.clone()).to_string())
+        .collect::<Vec<String>>()
+        .join("\n");
+    if selection.kind == VisualKind::Line && !text.ends_with('\n') {
+        text.push('\n');
+    }
+    text
+}
+
+/// Removes the selection and returns the removed text. When `keep_line` is set, linewise
+/// selections leave an empty line behind to insert into.
+pub fn delete(sw: &mut Window, selection: &Selection, keep_line: bool) -> String {
+    let deleted = selected_text(sw, selection);
+    for range in selection.ranges.iter().rev() {
+        let mut range = range.clone();
+        if selection.kind == VisualKind::Line {
+            let ends_with_newline =
+                range.end > range.start && sw.text().char(range.end - 1) == '\n';
+            if keep_line && ends_with_newline {
+                range.end -= 1;
+            } else if !keep_line && !ends_with_newline && range.start > 0 {
+                // The last line has no newline of its own, remove the one before it instead.
+                range.start -= 1;
+            }
+        }
+        sw.cursor_char_index = range.start;
+        sw.remove(range);
+    }
+    if selection.kind == VisualKind::Line && !keep_line {
+        let line_index = sw
+            .text()
+            .char_to_line(sw.cursor_char_index.min(sw.text().len_chars()));
+        sw.cursor_char_index = sw.text().line_to_char(line_index);
+    }
+    deleted
+}
+
+fn indent_line(sw: &mut Window, line_index: usize) {
+    let text = sw.text();
+    let line = text.line(line_index);
+    if line.len_chars() == 0 || line.char(0) == '\n' {
+        return;
+    }
+    let indent = if sw.buffer().options.expandtab {
+        " ".repeat(sw.tab_width())
+    } else {
+        "\t".to_string()
+    };
+    sw.insert(sw.text().line_to_char(line_index), &indent);
+}
+
+fn dedent_line(sw: &mut Window, line_index: usize) {
+    let text = sw.text();
+    let line = text.line(line_index);
+    let to_remove = if line.chars().next() == Some('\t') {
+        1
+    } else {
+        line.chars()
+            .take(sw.tab_width())
+            .take_while(|c| *c == ' ')
+            .count()
+    };
+    let line_start = sw.text().line_to_char(line_index);
+    sw.remove(line_start..line_start + to_remove);
+}
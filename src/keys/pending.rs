@@ -0,0 +1,215 @@
+use super::{
+    action::Action,
+    keymap::{Binding, KeyChord, Keymap, Lookup},
+    motion::{FindChar, FindKind, Motion},
+    operator::Operator,
+    textobject::TextObject,
+};
+
+pub enum OperatorTarget {
+    Motion(Motion),
+    TextObject(TextObject),
+    /// The operator was repeated like in `dd`, it acts on whole lines
+    Lines,
+}
+
+pub enum Command {
+    Motion(Motion),
+    Action(Action),
+    /// An operator without a target, which acts on the selection in visual mode
+    Operator(Operator),
+    OperatorWith(Operator, OperatorTarget),
+    TextObject(TextObject),
+}
+
+pub struct ParsedCommand {
+    pub count: Option<usize>,
+    pub register: Option<char>,
+    pub command: Command,
+}
+
+pub enum Parse {
+    Complete(ParsedCommand),
+    Pending,
+    Invalid,
+}
+
+/// Reads a count from the start of `keys` and returns it along with the number of keys used.
+fn parse_count(keys: &[KeyChord]) -> (Option<usize>, usize) {
+    let mut count: Option<usize> = None;
+    let mut used = 0;
+    for key in keys {
+        match key.as_char().and_then(|c| c.to_digit(10)) {
+            Some(digit) if digit != 0 || count.is_some() => {
+                count = Some(
+                    count
+                        .unwrap_or(0)
+                        .saturating_mul(10)
+                        .saturating_add(digit as usize),
+                );
+                used += 1;
+            }
+            _ => break,
+        }
+    }
+    (count, used)
+}
+
+fn multiply_counts(a: Option<usize>, b: Option<usize>) -> Option<usize> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(a.saturating_mul(b)),
+        (a, b) => a.or(b),
+    }
+}
+
+/// Parses `[count]["x][count]command`, where command is a motion, an action or an operator.
+/// Outside of visual mode an operator must be followed by `[count]` and a motion, a text object
+/// or the operator itself again.
+pub fn parse(keys: &[KeyChord], keymap: &Keymap, visual: bool, force: bool) -> Parse {
+    let (count, used) = parse_count(keys);
+    let mut rest = &keys[used..];
+    let mut register = None;
+    if rest.first() == Some(&KeyChord::char('"')) {
+        match rest.get(1) {
+            None => return Parse::Pending,
+            Some(key) => match key.as_char() {
+                Some(c) if crate::frontend::register::Registers::is_valid_name(c) => {
+                    register = Some(c);
+                    rest = &rest[2..];
+                }
+                _ => return Parse::Invalid,
+            },
+        }
+    }
+    let (second_count, used) = parse_count(rest);
+    let count = multiply_counts(count, second_count);
+    rest = &rest[used..];
+    if rest.is_empty() {
+        return Parse::Pending;
+    }
+
+    if visual {
+        if let Some(parse) = parse_text_object(rest, |object| ParsedCommand {
+            count,
+            register,
+            command: Command::TextObject(object),
+        }) {
+            return parse;
+        }
+    }
+
+    let (binding, used) = match keymap.lookup(rest, |_| true, force) {
+        Lookup::Found(binding, used) => (binding, used),
+        Lookup::Pending => return Parse::Pending,
+        Lookup::NotFound => return Parse::Invalid,
+    };
+    let command_keys = &rest[..used];
+    rest = &rest[used..];
+
+    let command = match binding {
+        Binding::Motion(motion) => Command::Motion(motion),
+        Binding::FindChar(kind) => match find_char(kind, rest) {
+            Ok(motion) => {
+                rest = &rest[1..];
+                Command::Motion(motion)
+            }
+            Err(parse) => return parse,
+        },
+        Binding::Action(action) => Command::Action(action),
+        Binding::Insert(_) | Binding::Dialog(_) | Binding::Line(_) => return Parse::Invalid,
+        Binding::Operator(operator) if visual => Command::Operator(operator),
+        Binding::Operator(operator) => {
+            return parse_operator_target(rest, command_keys, keymap, force, |target, c| {
+                ParsedCommand {
+                    count: multiply_counts(count, c),
+                    register,
+                    command: Command::OperatorWith(operator, target),
+                }
+            })
+        }
+    };
+    if !rest.is_empty() {
+        return Parse::Invalid;
+    }
+    Parse::Complete(ParsedCommand {
+        count,
+        register,
+        command,
+    })
+}
+
+fn parse_operator_target(
+    keys: &[KeyChord],
+    operator_keys: &[KeyChord],
+    keymap: &Keymap,
+    force: bool,
+    finish: impl Fn(OperatorTarget, Option<usize>) -> ParsedCommand,
+) -> Parse {
+    let (count, used) = parse_count(keys);
+    let rest = &keys[used..];
+    if rest.is_empty() {
+        return Parse::Pending;
+    }
+
+    // `dd`, `>>` and `g~~` as well as `g~g~`
+    let last_operator_key = &operator_keys[operator_keys.len() - 1..];
+    if rest == operator_keys || rest == last_operator_key {
+        return Parse::Complete(finish(OperatorTarget::Lines, count));
+    }
+    if operator_keys.starts_with(rest) && !force {
+        return Parse::Pending;
+    }
+
+    if let Some(parse) = parse_text_object(rest, |object| {
+        finish(OperatorTarget::TextObject(object), count)
+    }) {
+        return parse;
+    }
+
+    let is_motion = |b: &Binding| matches!(b, Binding::Motion(_) | Binding::FindChar(_));
+    match keymap.lookup(rest, is_motion, force) {
+        Lookup::Found(Binding::Motion(motion), used) if used == rest.len() => {
+            Parse::Complete(finish(OperatorTarget::Motion(motion), count))
+        }
+        Lookup::Found(Binding::FindChar(kind), used) => match find_char(kind, &rest[used..]) {
+            Ok(motion) if used + 1 == rest.len() => {
+                Parse::Complete(finish(OperatorTarget::Motion(motion), count))
+            }
+            Ok(_) => Parse::Invalid,
+            Err(parse) => parse,
+        },
+        Lookup::Pending => Parse::Pending,
+        _ => Parse::Invalid,
+    }
+}
+
+/// Takes the char that `f`, `t`, `F` and `T` search for from the first of `keys`.
+fn find_char(kind: FindKind, keys: &[KeyChord]) -> Result<Motion, Parse> {
+    match keys.first() {
+        None => Err(Parse::Pending),
+        Some(key) => match key.as_char() {
+            Some(c) => Ok(Motion::Find(FindChar { kind, c })),
+            None => Err(Parse::Invalid),
+        },
+    }
+}
+
+/// Parses `i` or `a` followed by the key of a text object.
+fn parse_text_object(
+    keys: &[KeyChord],
+    finish: impl Fn(TextObject) -> ParsedCommand,
+) -> Option<Parse> {
+    let around = match keys[0].as_char() {
+        Some('i') => false,
+        Some('a') => true,
+        _ => return None,
+    };
+    let Some(key) = keys.get(1) else {
+        return Some(Parse::Pending);
+    };
+    let object = key.as_char().and_then(|c| TextObject::by_key(c, around));
+    Some(match object {
+        Some(object) if keys.len() == 2 => Parse::Complete(finish(object)),
+        _ => Parse::Invalid,
+    })
+}
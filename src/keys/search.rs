@@ -0,0 +1,137 @@
+use crossterm::event::KeyEvent;
+
+use crate::frontend::{
+    app::{App, Mode},
+    search::{self, Search},
+};
+
+use super::command::{
+    edit_command_line, finish_line_keys, next_line_key, push_line_key, LineAction, LineKey,
+};
+
+pub fn start_search(app: &mut App, backward: bool) {
+    let Some(sw) = app.selected_window() else {
+        app.log.log("Error: No open windows");
+        return;
+    };
+    app.current_mode = Mode::Search {
+        buffer: String::new(),
+        char_idx: 0,
+        backward,
+        origin: sw.cursor_char_index,
+    };
+}
+
+pub fn process_keys_search(event: KeyEvent, app: &mut App) -> bool {
+    if push_line_key(app, event) {
+        resolve_pending_keys(app, false);
+    }
+    false
+}
+
+/// Edits the pattern with the pending keys, moving to the first match of what is typed so
+/// far, and searches for it on Enter.
+pub fn resolve_pending_keys(app: &mut App, force: bool) {
+    while let Some(key) = next_line_key(app, force) {
+        let Mode::Search {
+            buffer,
+            char_idx,
+            backward,
+            origin,
+        } = &mut app.current_mode
+        else {
+            break;
+        };
+        let (backward, origin) = (*backward, *origin);
+        match key {
+            LineKey::Action(LineAction::Cancel) => {
+                app.current_mode = Mode::Normal;
+                if let Some(sw) = app.selected_window_mut() {
+                    sw.cursor_char_index = origin;
+                }
+            }
+            LineKey::Action(LineAction::Confirm) => {
+                let pattern = std::mem::take(buffer);
+                app.current_mode = Mode::Normal;
+                finish_search(app, pattern, backward, origin);
+            }
+            key => {
+                if edit_command_line(buffer, char_idx, key) {
+                    let pattern = buffer.clone();
+                    if let Some(sw) = app.selected_window_mut() {
+                        sw.cursor_char_index = origin;
+                        if !pattern.is_empty() {
+                            if let Some((start, _)) =
+                                sw.search_matches(&pattern).next_match(origin, backward, 1)
+                            {
+                                sw.cursor_char_index = start;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    let mode_left = !matches!(app.current_mode, Mode::Search { .. });
+    finish_line_keys(app, mode_left);
+}
+
+/// Makes `pattern` the last search and jumps to its first match. An empty pattern repeats the
+/// last search in the new direction.
+fn finish_search(app: &mut App, pattern: String, backward: bool, origin: usize) {
+    let pattern = if pattern.is_empty() {
+        match &app.search {
+            Some(search) => search.pattern.clone(),
+            None => {
+                app.log.log("Error: No previous search pattern");
+                return;
+            }
+        }
+    } else {
+        pattern
+    };
+    if let Err(e) = search::build_regex(&pattern) {
+        app.log
+            .log(format!("Error: Invalid pattern {pattern}: {e}"));
+        if let Some(sw) = app.selected_window_mut() {
+            sw.cursor_char_index = origin;
+        }
+        return;
+    }
+    app.search = Some(Search { pattern, backward });
+    if let Some(sw) = app.selected_window_mut() {
+        sw.cursor_char_index = origin;
+    }
+    search_next(app, 1, false);
+}
+
+/// Goes to the `count`th next match of the last search, `n` and `N`.
+pub fn search_next(app: &mut App, count: usize, reverse: bool) {
+    let Some(search) = &app.search else {
+        app.log.log("Error: No previous search pattern");
+        return;
+    };
+    let backward = search.backward != reverse;
+    let pattern = search.pattern.clone();
+    let Some(sw) = app.edit_windows.get_mut(app.selected_window) else {
+        app.log.log("Error: No open windows");
+        return;
+    };
+    app.highlight_search = true;
+    let cursor = sw.cursor_char_index;
+    match sw
+        .search_matches(&pattern)
+        .next_match(cursor, backward, count)
+    {
+        Some((start, wrapped)) => {
+            sw.cursor_char_index = start;
+            sw.desired_column = None;
+            if wrapped && backward {
+                app.log.log("Search hit TOP, continuing at BOTTOM");
+            } else if wrapped {
+                app.log.log("Search hit BOTTOM, continuing at TOP");
+            }
+        }
+        None => app.log.log(format!("Pattern not found: {pattern}")),
+    }
+}
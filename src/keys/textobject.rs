@@ -0,0 +1,391 @@
+use std::ops::Range;
+
+use tree_sitter::{Node, Tree};
+
+use crate::frontend::{language::SyntaxObject, window::Window};
+
+use super::motion::{char_class, CharClass};
+
+#[derive(Copy, Clone)]
+pub enum TextObject {
+    Word {
+        big: bool,
+        around: bool,
+    },
+    Pair {
+        open: char,
+        close: char,
+        around: bool,
+    },
+    Quote {
+        quote: char,
+        around: bool,
+    },
+    /// A node of the syntax tree, like a function or a parameter
+    Syntax {
+        object: SyntaxObject,
+        around: bool,
+    },
+}
+
+impl TextObject {
+    /// Maps the key typed after `i` or `a` to a text object.
+    pub fn by_key(key: char, around: bool) -> Option<TextObject> {
+        let pair = |open, close| TextObject::Pair {
+            open,
+            close,
+            around,
+        };
+        let syntax = |object| TextObject::Syntax { object, around };
+        Some(match key {
+            'w' => TextObject::Word { big: false, around },
+            'W' => TextObject::Word { big: true, around },
+            '(' | ')' => pair('(', ')'),
+            '[' | ']' => pair('[', ']'),
+            '{' | '}' => pair('{', '}'),
+            '<' | '>' => pair('<', '>'),
+            '"' | '\'' | '`' => TextObject::Quote { quote: key, around },
+            'f' => syntax(SyntaxObject::Function),
+            'c' => syntax(SyntaxObject::Class),
+            'a' => syntax(SyntaxObject::Parameter),
+            '/' => syntax(SyntaxObject::Comment),
+            'o' => syntax(SyntaxObject::Block),
+            _ => return None,
+        })
+    }
+
+    pub fn range(&self, sw: &Window, count: usize) -> Option<Range<usize>> {
+        if sw.text().len_chars() == 0 {
+            return None;
+        }
+        let cursor = sw.cursor_char_index.min(sw.text().len_chars() - 1);
+        match *self {
+            TextObject::Word { big, around } => word_range(sw, cursor, count, big, around),
+            TextObject::Pair {
+                open,
+                close,
+                around,
+            } => pair_range(sw, cursor, count, open, close, around),
+            TextObject::Quote { quote, around } => quote_range(sw, cursor, quote, around),
+            TextObject::Syntax { object, around } => {
+                syntax_range(sw, cursor, count, object, around)
+            }
+        }
+    }
+}
+
+/// Returns the end of the run of chars that share the class of the char at `start`.
+fn run_end(sw: &Window, start: usize, big: bool) -> usize {
+    let class = char_class(sw.text().char(start), big);
+    let mut end = start;
+    while end < sw.text().len_chars() && char_class(sw.text().char(end), big) == class {
+        end += 1;
+        if class == CharClass::Newline {
+            break;
+        }
+    }
+    end
+}
+
+fn word_range(
+    sw: &Window,
+    cursor: usize,
+    count: usize,
+    big: bool,
+    around: bool,
+) -> Option<Range<usize>> {
+    let class = char_class(sw.text().char(cursor), big);
+    if class == CharClass::Newline {
+        return None;
+    }
+    let mut start = cursor;
+    while start > 0 && char_class(sw.text().char(start - 1), big) == class {
+        start -= 1;
+    }
+    let mut end = cursor;
+    for _ in 0..count {
+        if end >= sw.text().len_chars() || sw.text().char(end) == '\n' {
+            break;
+        }
+        end = run_end(sw, end, big);
+        let trailing_whitespace = end < sw.text().len_chars()
+            && char_class(sw.text().char(end), big) == CharClass::Whitespace;
+        if around && class != CharClass::Whitespace && trailing_whitespace {
+            end = run_end(sw, end, big);
+        }
+    }
+    if around && class != CharClass::Whitespace && end == run_end(sw, start, big) {
+        // There was no whitespace after the word, take the one in front of it instead
+        while start > 0 && char_class(sw.text().char(start - 1), big) == CharClass::Whitespace {
+            start -= 1;
+        }
+    }
+    Some(start..end)
+}
+
+fn pair_range(
+    sw: &Window,
+    cursor: usize,
+    count: usize,
+    open: char,
+    close: char,
+    around: bool,
+) -> Option<Range<usize>> {
+    let mut open_index = cursor + 1;
+    for _ in 0..count {
+        let mut depth = 0;
+        let mut i = open_index;
+        open_index = loop {
+            if i == 0 {
+                return None;
+            }
+            i -= 1;
+            let c = sw.text().char(i);
+            if c == close && i != cursor {
+                depth += 1;
+            } else if c == open {
+                if depth == 0 {
+                    break i;
+                }
+                depth -= 1;
+            }
+        };
+    }
+
+    let mut depth = 0;
+    let mut close_index = None;
+    for (i, c) in sw.text().chars_at(open_index + 1).enumerate() {
+        if c == open {
+            depth += 1;
+        } else if c == close {
+            if depth == 0 {
+                close_index = Some(open_index + 1 + i);
+                break;
+            }
+            depth -= 1;
+        }
+    }
+    let close_index = close_index?;
+    if around {
+        Some(open_index..close_index + 1)
+    } else {
+        Some(open_index + 1..close_index)
+    }
+}
+
+fn quote_range(sw: &Window, cursor: usize, quote: char, around: bool) -> Option<Range<usize>> {
+    let line_index = sw.text().char_to_line(cursor);
+    let line_start = sw.text().line_to_char(line_index);
+    let mut quotes = Vec::new();
+    let mut escaped = false;
+    for (i, c) in sw.text().line(line_index).chars().enumerate() {
+        if c == quote && !escaped {
+            quotes.push(line_start + i);
+        }
+        escaped = c == '\\' && !escaped;
+    }
+    let (start, end) = quotes
+        .chunks_exact(2)
+        .map(|pair| (pair[0], pair[1]))
+        .find(|(_, end)| cursor <= *end)?;
+    if around {
+        Some(start..end + 1)
+    } else {
+        Some(start + 1..end)
+    }
+}
+
+fn node_range(sw: &Window, node: Node) -> Range<usize> {
+    sw.text().byte_to_char(node.start_byte())..sw.text().byte_to_char(node.end_byte())
+}
+
+/// Returns all nodes of `tree` in the order they start in.
+fn all_nodes(tree: &Tree) -> Vec<Node<'_>> {
+    let mut nodes = Vec::new();
+    let mut cursor = tree.walk();
+    loop {
+        nodes.push(cursor.node());
+        if cursor.goto_first_child() || cursor.goto_next_sibling() {
+            continue;
+        }
+        loop {
+            if !cursor.goto_parent() {
+                return nodes;
+            }
+            if cursor.goto_next_sibling() {
+                break;
+            }
+        }
+    }
+}
+
+fn is_syntax_object(node: Node, object: SyntaxObject, kinds: &[String]) -> bool {
+    let is_kind = |node: Node| kinds.iter().any(|kind| kind == node.kind());
+    match object {
+        // Parameters are the nodes in a parameter or argument list
+        SyntaxObject::Parameter => {
+            node.is_named() && !node.is_extra() && node.parent().is_some_and(is_kind)
+        }
+        _ => is_kind(node),
+    }
+}
+
+/// Returns the char indices at which the nodes making up `object` start, in order.
+pub fn syntax_object_starts(sw: &Window, object: SyntaxObject) -> Vec<usize> {
+    let language = sw.buffer().language;
+    let (Some(language), Some(tree)) = (language, sw.syntax_tree()) else {
+        return Vec::new();
+    };
+    let kinds = language.syntax_node_kinds(object);
+    let mut starts: Vec<usize> = all_nodes(&tree)
+        .into_iter()
+        .filter(|node| is_syntax_object(*node, object, kinds))
+        .map(|node| sw.text().byte_to_char(node.start_byte()))
+        .collect();
+    starts.dedup();
+    starts
+}
+
+/// Finds the `count`th node of `object` around the cursor, or the next one after the cursor
+/// if the cursor isn't inside of one.
+fn syntax_range(
+    sw: &Window,
+    cursor: usize,
+    count: usize,
+    object: SyntaxObject,
+    around: bool,
+) -> Option<Range<usize>> {
+    let language = sw.buffer().language?;
+    let tree = sw.syntax_tree()?;
+    let kinds = language.syntax_node_kinds(object);
+    let byte_index = sw.text().char_to_byte(cursor);
+    let mut ancestors = Vec::new();
+    let mut node = tree
+        .root_node()
+        .descendant_for_byte_range(byte_index, byte_index + 1);
+    while let Some(n) = node {
+        if is_syntax_object(n, object, kinds) {
+            ancestors.push(n);
+        }
+        node = n.parent();
+    }
+    let node = match ancestors.get(count - 1) {
+        Some(node) => *node,
+        None if ancestors.is_empty() => all_nodes(&tree).into_iter().find(|node| {
+            node.start_byte() > byte_index && is_syntax_object(*node, object, kinds)
+        })?,
+        None => *ancestors.last()?,
+    };
+
+    Some(match (object, around) {
+        (SyntaxObject::Parameter, true) => parameter_with_separator(sw, node),
+        (SyntaxObject::Comment, false) => comment_content(sw, node),
+        (SyntaxObject::Block, false) => inside_braces(sw, node_range(sw, node)),
+        (SyntaxObject::Function | SyntaxObject::Class, false) => {
+            let body = body_of(node)?;
+            inside_braces(sw, node_range(sw, body))
+        }
+        _ => node_range(sw, node),
+    })
+}
+
+/// Returns the body of a function or class, which is either its `body` field or the last
+/// node in braces below it.
+fn body_of(node: Node) -> Option<Node> {
+    let mut node = node;
+    loop {
+        if let Some(body) = node.child_by_field_name("body") {
+            return Some(body);
+        }
+        node = node.named_child(node.named_child_count().checked_sub(1)?)?;
+        if node.kind().ends_with("list") || node.kind() == "block" {
+            return Some(node);
+        }
+    }
+}
+
+/// Returns the text between the braces at the ends of `range`, like `i{`. If the braces are on
+/// lines of their own, only the lines between them are included.
+fn inside_braces(sw: &Window, range: Range<usize>) -> Range<usize> {
+    let braced = range.len() >= 2
+        && matches!(sw.text().char(range.start), '{' | '(' | '[')
+        && matches!(sw.text().char(range.end - 1), '}' | ')' | ']');
+    if !braced {
+        return range;
+    }
+    let mut start = range.start + 1;
+    let mut end = range.end - 1;
+    if sw.text().char(start) == '\n' {
+        start += 1;
+    }
+    let close_line_start = sw.text().line_to_char(sw.text().char_to_line(end));
+    if close_line_start >= start
+        && sw
+            .text()
+            .slice(close_line_start..end)
+            .chars()
+            .all(char::is_whitespace)
+    {
+        end = close_line_start;
+    }
+    start.min(end)..end
+}
+
+/// Returns the parameter along with the comma and whitespace that separate it from the next
+/// one, or from the previous one if it is the last.
+fn parameter_with_separator(sw: &Window, node: Node) -> Range<usize> {
+    let range = node_range(sw, node);
+    if let Some(comma) = node.next_sibling().filter(|n| n.kind() == ",") {
+        let end = match comma.next_named_sibling() {
+            Some(next) => next.start_byte(),
+            None => comma.end_byte(),
+        };
+        return range.start..sw.text().byte_to_char(end);
+    }
+    if let Some(comma) = node.prev_sibling().filter(|n| n.kind() == ",") {
+        let start = match comma.prev_named_sibling() {
+            Some(previous) => previous.end_byte(),
+            None => comma.start_byte(),
+        };
+        return sw.text().byte_to_char(start)..range.end;
+    }
+    range
+}
+
+/// Returns the text of a comment without the comment tokens of the language around it.
+fn comment_content(sw: &Window, node: Node) -> Range<usize> {
+    let range = node_range(sw, node);
+    let (line_token, block_tokens) = sw
+        .buffer()
+        .language
+        .map_or((None, None), |language| language.comment_tokens());
+    let text = sw.text();
+    let text = text.slice(range.clone());
+    let content = text.to_string();
+    let (start_token, end_token) = match block_tokens {
+        Some((start, end)) if content.starts_with(start) => (start, end),
+        _ => (line_token.unwrap_or_default(), ""),
+    };
+    // Doc comments like `///`, `//!` and `/**` repeat chars of the token or add a `!`
+    let is_marker = |c: char| c == '!' || start_token.contains(c);
+    let leading = content.strip_prefix(start_token).map_or(0, |rest| {
+        start_token.chars().count() + rest.chars().take_while(|c| is_marker(*c)).count()
+    });
+    let leading = leading
+        + text
+            .chars()
+            .skip(leading)
+            .take_while(|c| c.is_whitespace())
+            .count();
+    let mut end = range.end;
+    if !end_token.is_empty()
+        && content.len() >= start_token.len() + end_token.len()
+        && content.ends_with(end_token)
+    {
+        end -= end_token.chars().count();
+    }
+    while end > range.start + leading && sw.text().char(end - 1).is_whitespace() {
+        end -= 1;
+    }
+    (range.start + leading).min(end)..end
+}
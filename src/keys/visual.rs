@@ -0,0 +1,96 @@
+use std::time::Instant;
+
+use crossterm::event::{KeyEvent, KeyEventKind};
+
+use crate::frontend::app::{App, Mode};
+
+use super::{
+    action::Action,
+    keymap::{Binding, KeyChord},
+    operator::Operator,
+    pending::{self, Command, Parse, ParsedCommand},
+};
+
+pub const DEFAULT_BINDINGS: &[(&str, Binding)] = &[
+    ("d", Binding::Operator(Operator::Delete)),
+    ("x", Binding::Operator(Operator::Delete)),
+    ("y", Binding::Operator(Operator::Yank)),
+    ("c", Binding::Operator(Operator::Change)),
+    ("s", Binding::Operator(Operator::Change)),
+    (">", Binding::Operator(Operator::Indent)),
+    ("<lt>", Binding::Operator(Operator::Dedent)),
+    ("~", Binding::Operator(Operator::ToggleCase)),
+    ("u", Binding::Operator(Operator::Lowercase)),
+    ("U", Binding::Operator(Operator::Uppercase)),
+    ("v", Binding::Action(Action::VisualChar)),
+    ("V", Binding::Action(Action::VisualLine)),
+    ("<C-v>", Binding::Action(Action::VisualBlock)),
+    ("o", Binding::Action(Action::SwapSelectionEnds)),
+    ("<Esc>", Binding::Action(Action::ExitVisual)),
+    ("n", Binding::Action(Action::SearchNext)),
+    ("N", Binding::Action(Action::SearchPrevious)),
+    (":", Binding::Action(Action::EnterCommandMode)),
+];
+
+pub fn process_keys_visual(event: KeyEvent, app: &mut App) -> bool {
+    if let KeyEventKind::Press = event.kind {
+        app.pending_keys.push(KeyChord::from_event(event));
+        app.pending_keys_since = Some(Instant::now());
+        resolve_pending_keys(app, false);
+    }
+    false
+}
+
+/// Like `normal::resolve_pending_keys`, but operators act on the selection right away.
+pub fn resolve_pending_keys(app: &mut App, force: bool) {
+    match pending::parse(&app.pending_keys, &app.keymaps.visual, true, force) {
+        Parse::Pending if !force => return,
+        Parse::Complete(parsed) => {
+            app.pending_keys.clear();
+            execute(app, parsed);
+        }
+        Parse::Pending | Parse::Invalid => {}
+    }
+    app.pending_keys.clear();
+    app.pending_keys_since = None;
+}
+
+fn execute(app: &mut App, parsed: ParsedCommand) {
+    let Mode::Visual { kind } = app.current_mode else {
+        app.log.log("Error: Not in visual mode");
+        return;
+    };
+    let ParsedCommand {
+        count,
+        register,
+        command,
+    } = parsed;
+    if let Some(sw) = app.selected_window_mut() {
+        let anchor = sw.selection_anchor;
+        let cursor = sw.cursor_char_index;
+        sw.visual_marks = Some((anchor.min(cursor), anchor.max(cursor)));
+    }
+    match command {
+        Command::Motion(motion) => motion.move_cursor(app, count),
+        Command::Action(action) => action.run(app, count, register),
+        Command::Operator(operator) => {
+            let Some(sw) = app.selected_window() else {
+                app.current_mode = Mode::Normal;
+                return;
+            };
+            let selection = sw.selection(kind);
+            operator.apply(app, selection, register);
+        }
+        Command::TextObject(object) => {
+            if let Some(sw) = app.selected_window_mut() {
+                if let Some(range) = object.range(sw, count.unwrap_or(1)) {
+                    if !range.is_empty() {
+                        sw.selection_anchor = range.start;
+                        sw.cursor_char_index = range.end - 1;
+                    }
+                }
+            }
+        }
+        Command::OperatorWith(..) => {}
+    }
+}
@@ -2,6 +2,13 @@ use std::collections::VecDeque;
 
 use chrono::{DateTime, Local};
 
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum Severity {
+    Info,
+    Warning,
+    Error,
+}
+
 pub struct Log {
     internal: VecDeque<LogEntry>,
 }
@@ -14,20 +21,33 @@ impl Log {
     }
 
     pub fn log(&mut self, message: impl Into<String>) {
+        let message = message.into();
+        // Messages say how bad they are by their prefix, like "Error: No such tab"
+        let severity = if message.starts_with("Error") {
+            Severity::Error
+        } else if message.starts_with("Warning") || message.starts_with("There are unsaved") {
+            Severity::Warning
+        } else {
+            Severity::Info
+        };
         self.internal.push_front(LogEntry {
             timestamp: Local::now(),
-            message: message.into(),
+            message,
+            severity,
         });
     }
 
-    pub fn take_lines(&self) -> impl Iterator<Item = String> + '_ {
-        self.internal.iter().map(LogEntry::render_to_string)
+    pub fn take_entries(&self) -> impl Iterator<Item = (Severity, String)> + '_ {
+        self.internal
+            .iter()
+            .map(|entry| (entry.severity, entry.render_to_string()))
     }
 }
 
 struct LogEntry {
     timestamp: DateTime<Local>,
     message: String,
+    severity: Severity,
 }
 
 impl LogEntry {
@@ -1,5 +1,7 @@
 use std::error::Error;
 
+mod config;
+mod file;
 mod frontend;
 mod keys;
 mod log;